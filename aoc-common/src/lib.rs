@@ -0,0 +1,150 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A day's puzzle solution, split into parsing the input and solving each part.
+///
+/// Each part gets the same parsed input, so tooling can parse once and time or
+/// check the parts separately.
+pub trait Solution {
+    /// Day of the advent calendar (1-25)
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>>;
+}
+
+/// Returned by (and used as the answer type of) parts that haven't been solved yet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved yet")
+    }
+}
+
+impl Error for Unsolved {}
+
+/// Malformed puzzle input, pointing at where in the input things went wrong
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// The offending part of the input
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `snippet`, which must be a slice of `input`.
+    ///
+    /// Line and column are worked out from where `snippet` is within `input`, so parsers can
+    /// split and trim as they like as long as they report a slice of what they were given.
+    /// An empty slice at the end of a line or block reports missing (truncated) input.
+    pub fn at(day: u8, input: &str, snippet: &str, message: impl Into<String>) -> Self {
+        let offset = (snippet.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + snippet.len() <= input.len())
+            .filter(|&offset| input.is_char_boundary(offset))
+            .unwrap_or(input.len()); // not a slice of input, so the best we can do is the end
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: snippet.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if self.snippet.is_empty() {
+            write!(f, " (input ended)")
+        } else {
+            write!(f, " at {:?}", self.snippet)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a `T`, reporting where it was on failure
+pub fn parse_at<T>(day: u8, input: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| ParseError::at(day, input, token, format!("invalid number ({e})")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_locates_snippets() {
+        let input = "2-4,6-8\n2-3,4x5\n";
+        let (_, second_line) = input.split_once('\n').unwrap();
+        let err = ParseError::at(4, input, &second_line[4..7], "invalid range");
+        assert_eq!(2, err.line);
+        assert_eq!(5, err.column);
+        assert_eq!("4x5", err.snippet);
+        assert_eq!(
+            "day 04, line 2, column 5: invalid range at \"4x5\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_locates_truncated_input() {
+        let input = "addx 3\naddx";
+        let err = ParseError::at(10, input, &input[input.len()..], "missing argument");
+        assert_eq!((2, 5), (err.line, err.column));
+        assert_eq!(
+            "day 10, line 2, column 5: missing argument (input ended)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn it_locates_snippets_from_elsewhere_at_the_end() {
+        let input = "ééé\nab";
+        let elsewhere = String::from("x");
+        let err = ParseError::at(5, input, &elsewhere, "not in input");
+        assert_eq!((2, 3), (err.line, err.column));
+        // Pointing inside a char of the input can't be right either
+        let inside = unsafe { std::str::from_utf8_unchecked(&input.as_bytes()[1..1]) };
+        let err = ParseError::at(5, input, inside, "not on a char boundary");
+        assert_eq!((2, 3), (err.line, err.column));
+    }
+
+    #[test]
+    fn it_parses_numbers_at() {
+        let input = "1000\n2x00";
+        assert_eq!(Ok(1000), parse_at::<u64>(1, input, &input[..4]));
+        let err = parse_at::<u64>(1, input, &input[5..]).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+}
@@ -0,0 +1,95 @@
+use std::{error::Error, fs, io::ErrorKind, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Known answers, stored as TOML and keyed by day, part and a hash of the puzzle input
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    answers: Vec<Answer>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+impl Answers {
+    /// Loads answers from `path`, or starts out empty if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(toml) => Ok(toml::from_str(&toml)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input_hash == input_hash)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Stores an answer, replacing any previous answer for the same day, part and input
+    pub fn insert(&mut self, answer: Answer) {
+        self.answers.retain(|a| {
+            (a.day, a.part, &a.input_hash) != (answer.day, answer.part, &answer.input_hash)
+        });
+        self.answers.push(answer);
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input_hash).cmp(&(b.day, b.part, &b.input_hash)));
+    }
+}
+
+/// 64 bit FNV-1a hash of the input, as hex.
+///
+/// Unlike `DefaultHasher` it is stable across Rust versions, which matters as the hashes are
+/// stored in the answers file.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hash_is_stable() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+    }
+
+    #[test]
+    fn it_round_trips_answers() {
+        let mut answers = Answers::default();
+        answers.insert(Answer {
+            day: 10,
+            part: 2,
+            input_hash: input_hash("noop"),
+            answer: "#-\n-#".to_string(),
+        });
+        answers.insert(Answer {
+            day: 1,
+            part: 1,
+            input_hash: input_hash("1000"),
+            answer: "1000".to_string(),
+        });
+
+        let answers = toml::from_str::<Answers>(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(Some("#-\n-#"), answers.get(10, 2, &input_hash("noop")));
+        assert_eq!(Some("1000"), answers.get(1, 1, &input_hash("1000")));
+        assert_eq!(None, answers.get(1, 1, &input_hash("2000")));
+    }
+}
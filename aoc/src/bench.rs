@@ -0,0 +1,172 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Solution;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::days::with_solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum InputKind {
+    /// The puzzle's example, aocNN/example.txt
+    Example,
+    /// The real puzzle input, aocNN/input.txt
+    Real,
+}
+
+impl InputKind {
+    pub fn file_name(self) -> &'static str {
+        match self {
+            InputKind::Example => "example.txt",
+            InputKind::Real => "input.txt",
+        }
+    }
+}
+
+/// Timings for parsing and solving one day's input, repeated `runs` times
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub day: u8,
+    pub input: InputKind,
+    pub runs: usize,
+    pub parse: Timing,
+    /// `None` if the part is unsolved or fails for this input
+    pub part1: Option<Timing>,
+    pub part2: Option<Timing>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Timing {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub max_ns: u128,
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Timing {
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[samples.len() / 2].as_nanos(),
+            mean_ns: samples.iter().sum::<Duration>().as_nanos() / samples.len() as u128,
+            max_ns: samples[samples.len() - 1].as_nanos(),
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} (min {:?}, max {:?})",
+            Duration::from_nanos(self.median_ns as u64),
+            Duration::from_nanos(self.min_ns as u64),
+            Duration::from_nanos(self.max_ns as u64)
+        )
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let part = |timing: &Option<Timing>| {
+            timing
+                .as_ref()
+                .map_or("-".to_string(), |timing| timing.to_string())
+        };
+        write!(
+            f,
+            "Day {:02} {:?} input, median of {} runs\n  parse:  {}\n  part 1: {}\n  part 2: {}",
+            self.day,
+            self.input,
+            self.runs,
+            self.parse,
+            part(&self.part1),
+            part(&self.part2)
+        )
+    }
+}
+
+pub fn bench(
+    day: u8,
+    kind: InputKind,
+    input: &str,
+    runs: usize,
+) -> Result<BenchReport, Box<dyn Error>> {
+    let runs = runs.max(1);
+    let (parse, part1, part2) = with_solution!(day, S => bench_solution::<S>(input, runs))?;
+    Ok(BenchReport {
+        day,
+        input: kind,
+        runs,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+type Timings = (Timing, Option<Timing>, Option<Timing>);
+
+fn bench_solution<S: Solution>(input: &str, runs: usize) -> Result<Timings, Box<dyn Error>> {
+    let parsed = S::parse(input)?;
+    let parse = time(runs, || {
+        black_box(S::parse(black_box(input))).ok();
+    });
+
+    // Only time parts that succeed, failing parts may bail out early and look fast
+    let part1 = S::part1(&parsed).ok().map(|_| {
+        time(runs, || {
+            black_box(S::part1(black_box(&parsed))).ok();
+        })
+    });
+    let part2 = S::part2(&parsed).ok().map(|_| {
+        time(runs, || {
+            black_box(S::part2(black_box(&parsed))).ok();
+        })
+    });
+    Ok((parse, part1, part2))
+}
+
+fn time(runs: usize, mut f: impl FnMut()) -> Timing {
+    Timing::from_samples(
+        (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                f();
+                start.elapsed()
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_summarizes_samples() {
+        let timing = Timing::from_samples(
+            [4, 1, 3, 2, 10]
+                .into_iter()
+                .map(Duration::from_nanos)
+                .collect(),
+        );
+        assert_eq!(1, timing.min_ns);
+        assert_eq!(3, timing.median_ns);
+        assert_eq!(4, timing.mean_ns);
+        assert_eq!(10, timing.max_ns);
+    }
+
+    #[test]
+    fn it_skips_unsolved_parts() {
+        let report = bench(21, InputKind::Example, "root: 5", 2).unwrap();
+        assert!(report.part1.is_some());
+        assert!(report.part2.is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use std::error::Error;
+
+use aoc_common::Solution;
+
+/// Days that have a solution in this repo
+pub const DAYS: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 10, 11, 13, 21, 23];
+
+/// Evaluates `$body` with `$solution` as a type alias for the given day's `Solution`
+macro_rules! with_solution {
+    ($day:expr, $solution:ident => $body:expr) => {
+        match $day {
+            1 => {
+                type $solution = aoc01::Day01;
+                $body
+            }
+            2 => {
+                type $solution = aoc02::Day02;
+                $body
+            }
+            3 => {
+                type $solution = aoc03::Day03;
+                $body
+            }
+            4 => {
+                type $solution = aoc04::Day04;
+                $body
+            }
+            5 => {
+                type $solution = aoc05::Day05;
+                $body
+            }
+            6 => {
+                type $solution = aoc06::Day06;
+                $body
+            }
+            7 => {
+                type $solution = aoc07::Day07;
+                $body
+            }
+            10 => {
+                type $solution = aoc10::Day10;
+                $body
+            }
+            11 => {
+                type $solution = aoc11::Day11;
+                $body
+            }
+            13 => {
+                type $solution = aoc13::Day13;
+                $body
+            }
+            21 => {
+                type $solution = aoc21::Day21;
+                $body
+            }
+            23 => {
+                type $solution = aoc23::Day23;
+                $body
+            }
+            day => Err(format!("No solution for day {day}").into()),
+        }
+    };
+}
+
+pub(crate) use with_solution;
+
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, Box<dyn Error>> {
+    with_solution!(day, S => solve_part::<S>(part, input))
+}
+
+fn solve_part<S: Solution>(part: u8, input: &str) -> Result<String, Box<dyn Error>> {
+    let input = S::parse(input)?;
+    match part {
+        1 => Ok(S::part1(&input)?.to_string()),
+        2 => Ok(S::part2(&input)?.to_string()),
+        _ => Err(format!("No part {part}, there are only 2 parts per day").into()),
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    env,
+    error::Error,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use aoc_common::Solution;
+use clap::{Parser, Subcommand, ValueEnum};
+
+use answers::Answers;
+use bench::InputKind;
+
+mod answers;
+mod bench;
+mod days;
+mod verify;
+
+/// Advent of Code 2022 solutions
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or all days if no day is given
+    Run {
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Only run this part (1 or 2)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input, defaults to aocNN/input.txt
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Check each day's answers for its aocNN/input.txt against the known answers
+    Verify {
+        #[arg(short, long)]
+        day: Option<u8>,
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Store answers that aren't known yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and solving each part, on the example and real inputs
+    Bench {
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Which inputs to run against, defaults to both
+        #[arg(short, long, value_enum)]
+        input: Option<InputKind>,
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Step through a day 10 program, type "help" for the commands
+    Debug {
+        /// Program to debug, defaults to aoc10/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Save the day 10 CRT screen as a PPM image, optionally with a frame for every cycle
+    Render {
+        /// Program to render, defaults to aoc10/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, default_value = "crt.ppm")]
+        output: PathBuf,
+        /// Directory to write frame_NNNN.ppm images to, one per cycle
+        #[arg(long)]
+        frames: Option<PathBuf>,
+        /// Size in image pixels of each CRT pixel
+        #[arg(long, default_value_t = 10)]
+        scale: usize,
+    },
+    /// Explore the day 7 filesystem
+    Report {
+        #[arg(value_enum)]
+        report: Report,
+        /// Terminal output to read the filesystem from, defaults to aoc07/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Number of directories to show in the top report
+        #[arg(short, long, default_value_t = 10)]
+        top: usize,
+    },
+    /// Recreate the day 7 filesystem on disk, with sparse files of the recorded sizes
+    Replay {
+        /// Terminal output to read the filesystem from, defaults to aoc07/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Empty or new directory to create it in, defaults to aoc07-fs in the temp directory
+        target: Option<PathBuf>,
+    },
+    /// Print a day 7 style terminal session exploring a real directory
+    Scan { dir: PathBuf },
+    /// Show step by step how day 13 packet pairs are compared
+    Explain {
+        /// Packets to compare, defaults to aoc13/input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Only explain this pair (1-based)
+        #[arg(short, long)]
+        pair: Option<usize>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Report {
+    /// Every file and directory, with sizes
+    Tree,
+    /// Directories by size, like `du -h | sort -hr`
+    Du,
+    /// The largest directories
+    Top,
+    /// Total file size per extension
+    Ext,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    match Cli::parse().command {
+        Command::Run { day, part, input } => {
+            let days = day.map_or(days::DAYS.to_vec(), |day| vec![day]);
+            for day in days {
+                let input = fs::read_to_string(input.clone().unwrap_or_else(|| input_path(day)))?;
+                for part in part.map_or(vec![1, 2], |part| vec![part]) {
+                    match days::solve(day, part, &input) {
+                        Ok(answer) if answer.contains('\n') => {
+                            println!("Day {day:02} part {part}:\n{answer}")
+                        }
+                        Ok(answer) => println!("Day {day:02} part {part}: {answer}"),
+                        Err(e) => println!("Day {day:02} part {part}: {e}"),
+                    }
+                }
+            }
+        }
+        Command::Verify {
+            day,
+            answers: answers_path,
+            record,
+        } => {
+            let mut answers = Answers::load(&answers_path)?;
+            let mut failures = 0;
+            for day in day.map_or(days::DAYS.to_vec(), |day| vec![day]) {
+                let input = fs::read_to_string(input_path(day))?;
+                for part in [1, 2] {
+                    let status = verify::verify(&answers, day, part, &input);
+                    println!("Day {day:02} part {part}: {status}");
+                    if status.is_failure() {
+                        failures += 1;
+                    }
+                    if record {
+                        verify::record(&mut answers, day, part, &input, &status);
+                    }
+                }
+            }
+            if record {
+                answers.save(&answers_path)?;
+            }
+            if failures > 0 {
+                return Err(format!("{failures} answer(s) failed verification").into());
+            }
+        }
+        Command::Bench {
+            day,
+            input,
+            runs,
+            json,
+        } => {
+            let kinds = input.map_or(vec![InputKind::Example, InputKind::Real], |kind| vec![kind]);
+            let mut reports = Vec::new();
+            for day in day.map_or(days::DAYS.to_vec(), |day| vec![day]) {
+                for &kind in &kinds {
+                    let path = PathBuf::from(format!("aoc{day:02}")).join(kind.file_name());
+                    let report = bench::bench(day, kind, &fs::read_to_string(path)?, runs)?;
+                    if !json {
+                        println!("{report}");
+                    }
+                    reports.push(report);
+                }
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&reports)?);
+            }
+        }
+        Command::Debug { input } => {
+            let program = fs::read_to_string(input.unwrap_or_else(|| input_path(10)))?;
+            let program = aoc10::Day10::parse(&program)?;
+            aoc10::repl(&program, io::stdin().lock(), io::stdout())?;
+        }
+        Command::Render {
+            input,
+            output,
+            frames,
+            scale,
+        } => {
+            let program = fs::read_to_string(input.unwrap_or_else(|| input_path(10)))?;
+            let program = aoc10::Day10::parse(&program)?;
+            let config = aoc10::CrtConfig::default();
+            let mut out = BufWriter::new(File::create(&output)?);
+            aoc10::write_ppm(&aoc10::render(&program, &config), scale, &mut out)?;
+            out.flush()?;
+            println!("Wrote {}", output.display());
+            if let Some(dir) = frames {
+                let count = aoc10::write_frames(&program, &config, scale, &dir)?;
+                println!("Wrote {count} frames to {}", dir.display());
+            }
+        }
+        Command::Report { report, input, top } => {
+            let output = fs::read_to_string(input.unwrap_or_else(|| input_path(7)))?;
+            let filesystem = aoc07::Day07::parse(&output)?;
+            match report {
+                Report::Tree => print!("{}", aoc07::tree_view(&filesystem)),
+                Report::Du => print!("{}", aoc07::du_listing(&filesystem)),
+                Report::Top => {
+                    for (path, size) in aoc07::largest_dirs(&filesystem, top) {
+                        println!("{size:>10}  {path}");
+                    }
+                }
+                Report::Ext => {
+                    for (ext, size) in aoc07::size_by_extension(&filesystem) {
+                        let ext = if ext.is_empty() { "(none)" } else { &ext };
+                        println!("{size:>10}  {ext}");
+                    }
+                }
+            }
+        }
+        Command::Replay { input, target } => {
+            let output = fs::read_to_string(input.unwrap_or_else(|| input_path(7)))?;
+            let filesystem = aoc07::Day07::parse(&output)?;
+            let target = target.unwrap_or_else(|| env::temp_dir().join("aoc07-fs"));
+            aoc07::materialize(&filesystem, &target)?;
+            println!("Wrote {}", target.display());
+        }
+        Command::Scan { dir } => print!("{}", aoc07::transcript(&dir)?),
+        Command::Explain { input, pair } => {
+            let packets = fs::read_to_string(input.unwrap_or_else(|| input_path(13)))?;
+            let pairs = aoc13::Day13::parse(&packets)?;
+            for (i, (left, right)) in pairs.iter().enumerate() {
+                if pair.is_none_or(|pair| pair == i + 1) {
+                    println!("== Pair {} ==", i + 1);
+                    println!("{}\n", aoc13::compare_explained(left, right));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("aoc{day:02}/input.txt"))
+}
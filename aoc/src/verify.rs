@@ -0,0 +1,71 @@
+use std::fmt::{self, Display, Formatter};
+
+use aoc_common::Unsolved;
+
+use crate::{
+    answers::{input_hash, Answer, Answers},
+    days,
+};
+
+/// Result of checking a day's answer against the known answers
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// Solved, but there's no known answer for this input to check against
+    Unknown {
+        actual: String,
+    },
+    Unsolved,
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL, expected {expected:?} but got {actual:?}")
+            }
+            Status::Unknown { actual } => write!(f, "unknown, got {actual:?}"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Error(e) => write!(f, "ERROR, {e}"),
+        }
+    }
+}
+
+pub fn verify(answers: &Answers, day: u8, part: u8, input: &str) -> Status {
+    match days::solve(day, part, input) {
+        Ok(actual) => match answers.get(day, part, &input_hash(input)) {
+            Some(expected) if expected == actual => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+            None => Status::Unknown { actual },
+        },
+        Err(e) if e.is::<Unsolved>() => Status::Unsolved,
+        Err(e) => Status::Error(e.to_string()),
+    }
+}
+
+/// Stores the answer of an `Unknown` status so it's checked from now on
+pub fn record(answers: &mut Answers, day: u8, part: u8, input: &str, status: &Status) {
+    if let Status::Unknown { actual } = status {
+        answers.insert(Answer {
+            day,
+            part,
+            input_hash: input_hash(input),
+            answer: actual.clone(),
+        });
+    }
+}
@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u64>; // calories carried by each elf
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split("\n\n")
+            .map(|elf| {
+                elf.lines()
+                    .map(|cal| parse_at::<u64>(Self::DAY, input, cal))
+                    .sum::<Result<u64, _>>()
+            })
+            .collect()
+    }
+
+    fn part1(calories_by_elf: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_max_calories(calories_by_elf).ok_or("No elves in input")?)
+    }
+
+    fn part2(calories_by_elf: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2_sum_top_3_calories(calories_by_elf))
+    }
+}
+
+pub fn part_1_max_calories(calories_by_elf: &[u64]) -> Option<u64> {
+    calories_by_elf.iter().max().copied()
+}
+
+pub fn part_2_sum_top_3_calories(calories_by_elf: &[u64]) -> u64 {
+    let mut calories_by_elf = calories_by_elf.to_vec();
+    calories_by_elf.sort_unstable();
+    calories_by_elf.iter().rev().take(3).sum()
+}
@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use aoc_common::{ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(Rps, Xyz)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(strategy_guide: &str) -> Result<Self::Input, ParseError> {
+        let err = |token, msg| ParseError::at(Self::DAY, strategy_guide, token, msg);
+        strategy_guide
+            .lines()
+            .map(|round| {
+                if let [their, mine] = round.split(' ').collect::<Vec<_>>()[..] {
+                    let their = match their {
+                        "A" => Ok(Rps::Rock),
+                        "B" => Ok(Rps::Paper),
+                        "C" => Ok(Rps::Scissors),
+                        _ => Err(err(their, "Invalid first char in strategy round")),
+                    }?;
+                    let mine = match mine {
+                        "X" => Ok(Xyz::X),
+                        "Y" => Ok(Xyz::Y),
+                        "Z" => Ok(Xyz::Z),
+                        _ => Err(err(mine, "Invalid last char in strategy round")),
+                    }?;
+                    return Ok((their, mine));
+                }
+                Err(err(round, "Invalid format for round"))
+            })
+            .collect()
+    }
+
+    fn part1(strategy_guide: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1(strategy_guide))
+    }
+
+    fn part2(strategy_guide: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2(strategy_guide))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Rps {
+    fn to_score(self) -> usize {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    fn fight(self, other: Self) -> usize {
+        self.to_score()
+            + match (self, other) {
+                // Win
+                (Self::Rock, Self::Scissors)
+                | (Self::Paper, Self::Rock)
+                | (Self::Scissors, Self::Paper) => 6,
+                // Tie
+                _ if self == other => 3,
+                // Loose
+                _ => 0,
+            }
+    }
+}
+
+/// Second column of the strategy guide, which part 1 and part 2 interpret differently
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Xyz {
+    X,
+    Y,
+    Z,
+}
+
+pub fn part_1(strategy_guide: &[(Rps, Xyz)]) -> usize {
+    strategy_guide
+        .iter()
+        .map(|&(their, mine)| {
+            let mine = match mine {
+                Xyz::X => Rps::Rock,
+                Xyz::Y => Rps::Paper,
+                Xyz::Z => Rps::Scissors,
+            };
+            mine.fight(their)
+        })
+        .sum()
+}
+
+pub fn part_2(strategy_guide: &[(Rps, Xyz)]) -> usize {
+    strategy_guide
+        .iter()
+        .map(|&(their, result)| {
+            let mine = match result {
+                Xyz::X => match their {
+                    // need to lose
+                    Rps::Rock => Rps::Scissors,
+                    Rps::Paper => Rps::Rock,
+                    Rps::Scissors => Rps::Paper,
+                },
+                Xyz::Y => their, // tied
+                Xyz::Z => match their {
+                    // need to win
+                    Rps::Rock => Rps::Paper,
+                    Rps::Paper => Rps::Scissors,
+                    Rps::Scissors => Rps::Rock,
+                },
+            };
+            mine.fight(their)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_STRATEGY_GUIDE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_invalid_rounds() {
+        let err = Day02::parse("A Y\nB Q\n").unwrap_err();
+        assert_eq!((2, 3, "Q"), (err.line, err.column, err.snippet.as_str()));
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        assert_eq!(15, part_1(&Day02::parse(TEST_STRATEGY_GUIDE).unwrap()));
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        assert_eq!(12, part_2(&Day02::parse(TEST_STRATEGY_GUIDE).unwrap()));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashSet, error::Error};
+
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(rucksacks: &str) -> Result<Self::Input, ParseError> {
+        rucksacks
+            .lines()
+            .map(|rucksack| {
+                if let Some((i, c)) = rucksack
+                    .char_indices()
+                    .find(|(_, c)| !c.is_ascii_alphabetic())
+                {
+                    let item = &rucksack[i..i + c.len_utf8()];
+                    return Err(ParseError::at(
+                        Self::DAY,
+                        rucksacks,
+                        item,
+                        "Items must be a-z or A-Z",
+                    ));
+                }
+                if !rucksack.len().is_multiple_of(2) {
+                    return Err(ParseError::at(
+                        Self::DAY,
+                        rucksacks,
+                        rucksack,
+                        "Rucksack must have an even number of items",
+                    ));
+                }
+                Ok(rucksack.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        part_1_sum_pri_common_cpt(rucksacks)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        part_2_sum_pri_badges(rucksacks)
+    }
+}
+
+trait Prioritizable {
+    fn priority(&self) -> u8;
+}
+
+impl Prioritizable for char {
+    fn priority(&self) -> u8 {
+        if self.is_lowercase() {
+            (*self as u8) - b'a' + 1u8 // a-z -> 1-26
+        } else if self.is_uppercase() {
+            (*self as u8) - b'A' + 27u8 // A-Z -> 27-52
+        } else {
+            unreachable!();
+        }
+    }
+}
+
+pub fn part_1_sum_pri_common_cpt(rucksacks: &[String]) -> Result<usize, Box<dyn Error>> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let (cpt1, cpt2) = rucksack.split_at(rucksack.len() / 2);
+            let common = cpt1
+                .chars()
+                .collect::<HashSet<_>>()
+                .intersection(&cpt2.chars().collect::<HashSet<_>>())
+                .next()
+                .copied()
+                .ok_or_else(|| format!("rucksack {} compartments had nothing in common", i + 1))?;
+            Ok(common.priority() as usize)
+        })
+        .sum()
+}
+
+pub fn part_2_sum_pri_badges(rucksacks: &[String]) -> Result<usize, Box<dyn Error>> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err("Rucksacks don't divide evenly into groups of three elves".into());
+    }
+
+    rucksacks
+        .iter()
+        .tuples()
+        .enumerate()
+        .map(|(i, (first, second, third))| {
+            let badge = first
+                .chars()
+                .collect::<HashSet<_>>()
+                .intersection(&second.chars().collect::<HashSet<_>>())
+                .copied()
+                .collect::<HashSet<_>>()
+                .intersection(&third.chars().collect::<HashSet<_>>())
+                .next()
+                .copied()
+                .ok_or_else(|| format!("group {} has no badge", i + 1))?;
+            Ok(badge.priority() as usize)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_RUCKSACKS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn priority_works() {
+        assert_eq!(1, 'a'.priority());
+        assert_eq!(2, 'b'.priority());
+        assert_eq!(27, 'A'.priority());
+        assert_eq!(28, 'B'.priority());
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        assert_eq!(
+            157,
+            part_1_sum_pri_common_cpt(&Day03::parse(TEST_RUCKSACKS).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        assert_eq!(
+            70,
+            part_2_sum_pri_badges(&Day03::parse(TEST_RUCKSACKS).unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::{collections::HashSet, error::Error, ops::RangeInclusive};
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(assignment_pairs: &str) -> Result<Self::Input, ParseError> {
+        assignment_pairs
+            .lines()
+            .map(|pair| to_range_pair(assignment_pairs, pair))
+            .collect()
+    }
+
+    fn part1(assignment_pairs: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_count_full_overlaps(assignment_pairs))
+    }
+
+    fn part2(assignment_pairs: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2_count_any_overlaps(assignment_pairs))
+    }
+}
+
+fn to_range_pair(
+    input: &str,
+    pair: &str,
+) -> Result<(RangeInclusive<usize>, RangeInclusive<usize>), ParseError> {
+    let into_range = |r: &str| {
+        let (from, to) = r
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(Day04::DAY, input, r, "Expected a range like 2-4"))?;
+        let range =
+            parse_at::<usize>(Day04::DAY, input, from)?..=parse_at::<usize>(Day04::DAY, input, to)?;
+        if range.is_empty() {
+            return Err(ParseError::at(Day04::DAY, input, r, "Range is empty"));
+        }
+        Ok(range)
+    };
+
+    let (p1, p2) = pair
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(Day04::DAY, input, pair, "Expected a pair like 2-4,6-8"))?;
+    Ok((into_range(p1)?, into_range(p2)?))
+}
+
+pub fn part_1_count_full_overlaps(
+    assignment_pairs: &[(RangeInclusive<usize>, RangeInclusive<usize>)],
+) -> usize {
+    assignment_pairs
+        .iter()
+        .filter(|(p1, p2)| {
+            (p1.contains(&p2.clone().next().unwrap()) && p1.contains(&p2.clone().last().unwrap()))
+                || (p2.contains(&p1.clone().next().unwrap())
+                    && p2.contains(&p1.clone().last().unwrap()))
+        })
+        .count()
+}
+
+pub fn part_2_count_any_overlaps(
+    assignment_pairs: &[(RangeInclusive<usize>, RangeInclusive<usize>)],
+) -> usize {
+    assignment_pairs
+        .iter()
+        .filter(|(p1, p2)| {
+            p1.clone()
+                .collect::<HashSet<_>>()
+                .intersection(&p2.clone().collect::<HashSet<_>>())
+                .next()
+                .is_some()
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ASSIGNMENT_PAIRS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_malformed_pairs() {
+        let err = Day04::parse("2-4,6-8\n2-3,4+5").unwrap_err();
+        assert_eq!((2, 5, "4+5"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = Day04::parse("2-4,6-8\n2-3").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        assert_eq!(
+            2,
+            part_1_count_full_overlaps(&Day04::parse(TEST_ASSIGNMENT_PAIRS).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        assert_eq!(
+            4,
+            part_2_count_any_overlaps(&Day04::parse(TEST_ASSIGNMENT_PAIRS).unwrap())
+        );
+    }
+}
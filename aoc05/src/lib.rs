@@ -0,0 +1,196 @@
+use std::error::Error;
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (drawing, rearrangement) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at(
+                Self::DAY,
+                input,
+                &input[input.len()..],
+                "Expected a blank line between drawing and rearrangement",
+            )
+        })?;
+        let stacks = parse_stacks(input, drawing)?;
+        let moves = parse_moves(input, rearrangement, stacks.len())?;
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(top_crates(&rearrange_1(
+            &procedure.stacks,
+            &procedure.moves,
+        )?))
+    }
+
+    fn part2(procedure: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(top_crates(&rearrange_2(
+            &procedure.stacks,
+            &procedure.moves,
+        )?))
+    }
+}
+
+/// Starting stacks of crates (bottom crate first) and the moves to perform on them
+#[derive(Clone, Debug, PartialEq)]
+pub struct Procedure {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|x| x.last()).collect()
+}
+
+fn parse_stacks(input: &str, drawing: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let labels = drawing.lines().last().unwrap_or_default();
+    let offsets = labels
+        .char_indices()
+        .filter(|&(_, c)| c.is_ascii_digit())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if offsets.is_empty() {
+        return Err(ParseError::at(
+            Day05::DAY,
+            input,
+            labels,
+            "Expected stack numbers below the drawing",
+        ));
+    }
+
+    Ok(offsets
+        .iter()
+        .map(|&offset| {
+            drawing
+                .lines()
+                .rev()
+                .filter_map(|l| l.chars().nth(offset).filter(|crt| crt.is_alphabetic()))
+                .collect()
+        })
+        .collect())
+}
+
+fn parse_moves(
+    input: &str,
+    rearrangements: &str,
+    stack_count: usize,
+) -> Result<Vec<Move>, ParseError> {
+    let parse_stack_num = |token| {
+        let num = parse_at::<usize>(Day05::DAY, input, token)?;
+        if (1..=stack_count).contains(&num) {
+            Ok(num)
+        } else {
+            Err(ParseError::at(
+                Day05::DAY,
+                input,
+                token,
+                format!("No such stack, expected 1 to {stack_count}"),
+            ))
+        }
+    };
+
+    rearrangements
+        .lines()
+        .map(|cmd| {
+            if let ["move", count, "from", from, "to", to] =
+                cmd.split_whitespace().collect::<Vec<_>>()[..]
+            {
+                Ok(Move {
+                    count: parse_at(Day05::DAY, input, count)?,
+                    from: parse_stack_num(from)?,
+                    to: parse_stack_num(to)?,
+                })
+            } else {
+                Err(ParseError::at(
+                    Day05::DAY,
+                    input,
+                    cmd,
+                    "Expected a rearrangement like \"move 1 from 2 to 1\"",
+                ))
+            }
+        })
+        .collect()
+}
+
+fn pop_crate(stacks: &mut [Vec<char>], from: usize) -> Result<char, Box<dyn Error>> {
+    stacks[from - 1]
+        .pop()
+        .ok_or_else(|| format!("Tried to move a crate from empty stack {from}").into())
+}
+
+pub fn rearrange_1(stacks: &[Vec<char>], moves: &[Move]) -> Result<Vec<Vec<char>>, Box<dyn Error>> {
+    let mut stacks = stacks.to_vec();
+    for &Move { count, from, to } in moves {
+        for _ in 0..count {
+            let crt = pop_crate(&mut stacks, from)?;
+            stacks[to - 1].push(crt);
+        }
+    }
+    Ok(stacks)
+}
+
+pub fn rearrange_2(stacks: &[Vec<char>], moves: &[Move]) -> Result<Vec<Vec<char>>, Box<dyn Error>> {
+    let mut stacks = stacks.to_vec();
+    for &Move { count, from, to } in moves {
+        let mut moving = vec![];
+        for _ in 0..count {
+            moving.push(pop_crate(&mut stacks, from)?);
+        }
+        for crt in moving.into_iter().rev() {
+            stacks[to - 1].push(crt);
+        }
+    }
+    Ok(stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_REARRANGEMENT_DRAWING: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_invalid_rearrangements() {
+        let input = TEST_REARRANGEMENT_DRAWING.replace("move 2 from 2 to 1", "move 2 from 4 to 1");
+        let err = Day05::parse(&input).unwrap_err();
+        assert_eq!((8, 13, "4"), (err.line, err.column, err.snippet.as_str()));
+
+        let input = TEST_REARRANGEMENT_DRAWING.replace("move 1 from 1 to 2", "move 1 from");
+        let err = Day05::parse(&input).unwrap_err();
+        assert_eq!((9, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        let procedure = Day05::parse(TEST_REARRANGEMENT_DRAWING).unwrap();
+        assert_eq!(
+            "CMZ",
+            top_crates(&rearrange_1(&procedure.stacks, &procedure.moves).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        let procedure = Day05::parse(TEST_REARRANGEMENT_DRAWING).unwrap();
+        assert_eq!(
+            "MCD",
+            top_crates(&rearrange_2(&procedure.stacks, &procedure.moves).unwrap())
+        );
+    }
+}
@@ -1,21 +1,41 @@
 use std::error::Error;
-use std::{fs, iter};
+use std::iter;
 
+use aoc_common::{ParseError, Solution};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    println!("Part 1: {:?}", part_1_find_start_of_packet(&input));
-    println!("Part 2: {:?}", part_2_find_start_of_msg(&input));
-    Ok(())
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(datastream: &str) -> Result<Self::Input, ParseError> {
+        Ok(datastream.trim_end().to_string())
+    }
+
+    fn part1(datastream: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_find_start_of_packet(datastream)
+            .0
+            .ok_or("No start-of-packet marker found")?)
+    }
+
+    fn part2(datastream: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2_find_start_of_msg(datastream)
+            .0
+            .ok_or("No start-of-message marker found")?)
+    }
 }
 
-fn part_1_find_start_of_packet(datastream: &str) -> (Option<usize>, Vec<char>) {
+pub fn part_1_find_start_of_packet(datastream: &str) -> (Option<usize>, Vec<char>) {
     find_first_unique::<4>(datastream)
 }
 
-fn part_2_find_start_of_msg(datastream: &str) -> (Option<usize>, Vec<char>) {
+pub fn part_2_find_start_of_msg(datastream: &str) -> (Option<usize>, Vec<char>) {
     find_first_unique::<14>(datastream)
 }
 
@@ -47,7 +67,7 @@ fn find_first_unique<const N: usize>(s: &str) -> (Option<usize>, Vec<char>) {
 mod tests {
     use super::*;
 
-    const TEST_DATASTREAM: &'static str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    const TEST_DATASTREAM: &str = include_str!("../example.txt");
 
     #[test]
     fn it_works_with_example_1() {
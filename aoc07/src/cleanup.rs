@@ -0,0 +1,298 @@
+use std::{collections::HashMap, ops::Range};
+
+use slab_tree::NodeId;
+
+use crate::{FileNode, VirtualFs};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CleanupConfig {
+    /// Total disk space
+    pub capacity: usize,
+    /// Unused space needed for the update
+    pub required: usize,
+    pub strategy: Strategy,
+    /// Only consider directories without subdirectories
+    pub leaf_dirs_only: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// The smallest single directory that frees up enough space
+    SmallestDir,
+    /// The directories that together free up enough space while deleting as little as
+    /// possible. Never picks a directory inside another.
+    MinimalSet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deletion {
+    /// Absolute paths of the directories to delete
+    pub paths: Vec<String>,
+    pub freed: usize,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        CleanupConfig {
+            capacity: 70_000_000,
+            required: 30_000_000,
+            strategy: Strategy::SmallestDir,
+            leaf_dirs_only: false,
+        }
+    }
+}
+
+/// Directories to delete to get enough unused space, `None` if that's impossible
+pub fn plan_deletion(fs: &VirtualFs, config: &CleanupConfig) -> Option<Deletion> {
+    let unused = config.capacity.saturating_sub(fs.size(fs.root().node_id()));
+    let needed = config.required.saturating_sub(unused);
+    if needed == 0 {
+        return Some(Deletion {
+            paths: Vec::new(),
+            freed: 0,
+        });
+    }
+
+    let candidates = candidates(fs, config.leaf_dirs_only);
+    let chosen = match config.strategy {
+        Strategy::SmallestDir => candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.size >= needed)
+            .min_by_key(|(_, c)| c.size)
+            .map(|(i, _)| vec![i]),
+        Strategy::MinimalSet => {
+            // Only totals below the best single directory can improve on it
+            let limit = candidates
+                .iter()
+                .map(|c| c.size)
+                .filter(|&size| size >= needed)
+                .min()
+                .unwrap_or(fs.size(fs.root().node_id()));
+            let all = 0..candidates.len();
+            let sums = subset_sums(&candidates, all.clone(), limit);
+            let freed = (needed..=limit).find(|&sum| sums.get(sum))?;
+            let mut chosen = Vec::new();
+            choose(&candidates, all, freed, &mut chosen);
+            Some(chosen)
+        }
+    }?;
+
+    Some(Deletion {
+        freed: chosen.iter().map(|&i| candidates[i].size).sum(),
+        // Only the chosen paths are worked out, since each one walks up to the root
+        paths: chosen
+            .into_iter()
+            .map(|i| fs.path(candidates[i].node))
+            .collect(),
+    })
+}
+
+struct Candidate {
+    node: NodeId,
+    size: usize,
+    /// Index of the first candidate after this one that isn't inside it
+    next_outside: usize,
+}
+
+/// Directories that may be deleted, in pre-order so each one is followed by those inside it
+fn candidates(fs: &VirtualFs, leaf_dirs_only: bool) -> Vec<Candidate> {
+    let is_dir = |data: &FileNode| matches!(data, FileNode::Dir(_));
+    let mut depths = HashMap::new();
+    let mut candidates: Vec<Candidate> = Vec::new();
+    // Candidates containing the current node, as index and depth
+    let mut containing: Vec<(usize, usize)> = Vec::new();
+
+    for node in fs.root().traverse_pre_order() {
+        let depth = node.parent().map_or(0, |p| depths[&p.node_id()] + 1);
+        depths.insert(node.node_id(), depth);
+        if !is_dir(node.data()) || leaf_dirs_only && node.children().any(|c| is_dir(c.data())) {
+            continue;
+        }
+
+        while let Some(&(i, _)) = containing.last().filter(|&&(_, d)| d >= depth) {
+            candidates[i].next_outside = candidates.len();
+            containing.pop();
+        }
+        containing.push((candidates.len(), depth));
+        candidates.push(Candidate {
+            node: node.node_id(),
+            size: fs.size(node.node_id()),
+            next_outside: 0,
+        });
+    }
+    for (i, _) in containing {
+        candidates[i].next_outside = candidates.len();
+    }
+    candidates
+}
+
+/// Every total up to `limit` that deleting some of the candidates in `range` can free.
+/// `range` must hold whole subtrees. Goes through the candidates in order, either skipping one
+/// or deleting it and skipping everything inside it, like a 0/1 knapsack.
+fn subset_sums(candidates: &[Candidate], range: Range<usize>, limit: usize) -> Bitset {
+    // Totals that can be freed by the candidates before each index
+    let mut before = vec![None; range.len() + 1];
+    let mut nothing = Bitset::new(limit);
+    nothing.set(0);
+    before[0] = Some(nothing);
+
+    for i in range.clone() {
+        let sums = before[i - range.start].take().unwrap();
+        let candidate = &candidates[i];
+        before[candidate.next_outside - range.start]
+            .get_or_insert_with(|| Bitset::new(limit))
+            .union_shifted(&sums, candidate.size);
+        match &mut before[i + 1 - range.start] {
+            Some(next) => next.union_shifted(&sums, 0),
+            next => *next = Some(sums),
+        }
+    }
+    before[range.len()].take().unwrap()
+}
+
+/// Finds candidates in `range` that free exactly `freed`, which must be possible.
+/// Splits the subtrees in two halves and finds out how much each half frees, so only
+/// a few sets of totals are kept in memory at once.
+fn choose(candidates: &[Candidate], range: Range<usize>, freed: usize, chosen: &mut Vec<usize>) {
+    if freed == 0 {
+        return;
+    }
+    let trees = std::iter::successors(Some(range.start), |&i| {
+        Some(candidates[i].next_outside).filter(|&next| next < range.end)
+    })
+    .collect::<Vec<_>>();
+    if let [root] = trees[..] {
+        if candidates[root].size == freed {
+            chosen.push(root);
+        } else {
+            choose(candidates, root + 1..range.end, freed, chosen);
+        }
+        return;
+    }
+
+    let middle = trees[trees.len() / 2];
+    let (left, right) = (range.start..middle, middle..range.end);
+    let left_sums = subset_sums(candidates, left.clone(), freed);
+    let right_sums = subset_sums(candidates, right.clone(), freed);
+    let left_freed = (0..=freed)
+        .find(|&f| left_sums.get(f) && right_sums.get(freed - f))
+        .unwrap();
+    choose(candidates, left, left_freed, chosen);
+    choose(candidates, right, freed - left_freed, chosen);
+}
+
+/// Set of numbers up to a limit
+#[derive(Clone)]
+struct Bitset {
+    words: Vec<u64>,
+    limit: usize,
+}
+
+impl Bitset {
+    fn new(limit: usize) -> Self {
+        Bitset {
+            words: vec![0; limit / 64 + 1],
+            limit,
+        }
+    }
+
+    fn get(&self, n: usize) -> bool {
+        n <= self.limit && self.words[n / 64] & 1 << (n % 64) != 0
+    }
+
+    fn set(&mut self, n: usize) {
+        self.words[n / 64] |= 1 << (n % 64);
+    }
+
+    /// Adds every number of `other` plus `shift`, dropping those over the limit
+    fn union_shifted(&mut self, other: &Bitset, shift: usize) {
+        let (word_shift, bit_shift) = (shift / 64, shift % 64);
+        for i in (word_shift..self.words.len()).rev() {
+            let mut word = other.words[i - word_shift] << bit_shift;
+            if bit_shift > 0 && i > word_shift {
+                word |= other.words[i - word_shift - 1] >> (64 - bit_shift);
+            }
+            self.words[i] |= word;
+        }
+        // Clear what went past the limit in the last word
+        let last = self.words.len() - 1;
+        self.words[last] &= u64::MAX >> (63 - self.limit % 64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+
+    const NESTED: &str = "\
+$ cd /
+$ ls
+dir x
+dir y
+dir z
+$ cd x
+$ ls
+500 a
+$ cd ../y
+$ ls
+300 b
+$ cd ../z
+$ ls
+dir z1
+10 c
+$ cd z1
+$ ls
+250 d";
+
+    fn plan(required: usize, strategy: Strategy, leaf_dirs_only: bool) -> Option<Deletion> {
+        let fs = Day07::parse(NESTED).unwrap();
+        let config = CleanupConfig {
+            capacity: 1060,
+            required,
+            strategy,
+            leaf_dirs_only,
+        };
+        plan_deletion(&fs, &config)
+    }
+
+    fn deletion(paths: &[&str], freed: usize) -> Option<Deletion> {
+        Some(Deletion {
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            freed,
+        })
+    }
+
+    #[test]
+    fn it_deletes_the_smallest_dir() {
+        assert_eq!(
+            deletion(&["/"], 1060),
+            plan(555, Strategy::SmallestDir, false)
+        );
+        assert_eq!(
+            deletion(&["/x"], 500),
+            plan(400, Strategy::SmallestDir, false)
+        );
+        assert_eq!(None, plan(555, Strategy::SmallestDir, true));
+        assert_eq!(deletion(&[], 0), plan(0, Strategy::SmallestDir, false));
+    }
+
+    #[test]
+    fn it_deletes_minimal_sets() {
+        assert_eq!(
+            deletion(&["/y", "/z/z1"], 550),
+            plan(550, Strategy::MinimalSet, false)
+        );
+        assert_eq!(
+            deletion(&["/y", "/z"], 560),
+            plan(555, Strategy::MinimalSet, false)
+        );
+        assert_eq!(
+            deletion(&["/x", "/z/z1"], 750),
+            plan(555, Strategy::MinimalSet, true)
+        );
+        assert_eq!(None, plan(1061, Strategy::MinimalSet, false));
+    }
+}
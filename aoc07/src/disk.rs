@@ -0,0 +1,134 @@
+use std::{
+    fs::{self, OpenOptions},
+    io,
+    path::Path,
+};
+
+use crate::{FileNode, VirtualFs};
+
+/// Recreates the filesystem under `target`, with sparse files of the recorded sizes.
+/// `target` must be empty or not exist yet, so nothing already on disk is overwritten.
+pub fn materialize(fs: &VirtualFs, target: &Path) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+    if fs::read_dir(target)?.next().is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} is not empty", target.display()),
+        ));
+    }
+    for (_, path, node) in fs.walk().skip(1) {
+        let path = target.join(path.trim_start_matches('/'));
+        match node.data() {
+            FileNode::Dir(_) => fs::create_dir(path)?,
+            FileNode::File(size, _) => OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path)?
+                .set_len(*size as u64)?,
+        }
+    }
+    Ok(())
+}
+
+/// Terminal output of exploring a real directory with `cd` and `ls`, in the puzzle's format.
+/// Entries are listed by name, anything but files and directories (like symlinks) is left out.
+/// Names that can't be written in the transcript, not UTF-8 or with a newline, are an error.
+pub fn transcript(dir: &Path) -> io::Result<String> {
+    let mut output = "$ cd /\n".to_string();
+    explore(dir, &mut output)?;
+    Ok(output)
+}
+
+fn explore(dir: &Path, output: &mut String) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| {
+            let entry = entry?;
+            let name = entry.file_name().into_string().map_err(|name| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{name:?} isn't UTF-8"))
+            })?;
+            // A newline would end the line early and break up the listing
+            if name.contains('\n') {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{name:?} contains a newline"),
+                ));
+            }
+            Ok((name, entry.metadata()?))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    *output += "$ ls\n";
+    for (name, metadata) in &entries {
+        if metadata.is_dir() {
+            *output += &format!("dir {name}\n");
+        } else if metadata.is_file() {
+            *output += &format!("{} {name}\n", metadata.len());
+        }
+    }
+    for (name, metadata) in &entries {
+        if metadata.is_dir() {
+            *output += &format!("$ cd {name}\n");
+            explore(&dir.join(name), output)?;
+            *output += "$ cd ..\n";
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+
+    const TEST_TERMINAL_OUTPUT: &str = include_str!("../example.txt");
+
+    /// Every path with its size, to compare filesystems regardless of listing order
+    fn listing(fs: &VirtualFs) -> Vec<(String, usize)> {
+        let mut listing = fs
+            .root()
+            .traverse_pre_order()
+            .map(|n| (fs.path(n.node_id()), fs.size(n.node_id())))
+            .collect::<Vec<_>>();
+        listing.sort();
+        listing
+    }
+
+    #[test]
+    fn it_materializes_the_tree() {
+        let fs = Day07::parse(TEST_TERMINAL_OUTPUT).unwrap();
+        let target = tempfile::tempdir().unwrap();
+        materialize(&fs, target.path()).unwrap();
+
+        assert!(target.path().join("a/e").is_dir());
+        let metadata = fs::metadata(target.path().join("d/d.log")).unwrap();
+        assert_eq!(8033020, metadata.len());
+
+        let err = materialize(&fs, target.path()).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        let metadata = fs::metadata(target.path().join("d/d.log")).unwrap();
+        assert_eq!(8033020, metadata.len());
+    }
+
+    #[test]
+    fn it_round_trips_through_disk() {
+        let fs = Day07::parse(TEST_TERMINAL_OUTPUT).unwrap();
+        let target = tempfile::tempdir().unwrap();
+        materialize(&fs, target.path()).unwrap();
+
+        let output = transcript(target.path()).unwrap();
+        assert!(output.starts_with("$ cd /\n$ ls\ndir a\n14848514 b.txt\n"));
+        let scanned = Day07::parse(&output).unwrap();
+        assert_eq!(listing(&fs), listing(&scanned));
+    }
+
+    #[test]
+    fn it_rejects_names_with_newlines() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::write(dir.path().join("a/b\n1 c"), "").unwrap();
+        let err = transcript(dir.path()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+    }
+}
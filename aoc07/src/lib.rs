@@ -0,0 +1,268 @@
+use std::{collections::HashMap, error::Error};
+
+use aoc_common::{parse_at, ParseError, Solution};
+use slab_tree::{NodeId, Tree, TreeBuilder};
+
+mod cleanup;
+mod disk;
+mod report;
+mod vfs;
+
+pub use cleanup::{plan_deletion, CleanupConfig, Deletion, Strategy};
+pub use disk::{materialize, transcript};
+pub use report::{du_listing, human_size, largest_dirs, size_by_extension, tree_view};
+pub use vfs::{FsError, Stat, VirtualFs};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = VirtualFs;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(output: &str) -> Result<Self::Input, ParseError> {
+        parse_term(output).map(VirtualFs::from)
+    }
+
+    fn part1(fs: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_sum_dirs_size_lt_100_000(fs))
+    }
+
+    fn part2(fs: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let (_path, size) = part_2_find_dir_to_delete(fs).ok_or("No directory is big enough")?;
+        Ok(size)
+    }
+}
+
+pub fn part_1_sum_dirs_size_lt_100_000(fs: &VirtualFs) -> usize {
+    fs.root()
+        .traverse_level_order()
+        .filter_map(|x| match x.data() {
+            FileNode::Dir(_) => {
+                let size = fs.size(x.node_id());
+                if size < 100_000 {
+                    Some(size)
+                } else {
+                    None
+                }
+            }
+            FileNode::File(_, _) => None,
+        })
+        .sum()
+}
+
+/// Smallest directory that frees up enough space for the update, as its path and size
+pub fn part_2_find_dir_to_delete(fs: &VirtualFs) -> Option<(String, usize)> {
+    let deletion = plan_deletion(fs, &CleanupConfig::default())?;
+    Some((deletion.paths.into_iter().next()?, deletion.freed))
+}
+
+pub fn parse_term(output: &str) -> Result<Tree<FileNode>, ParseError> {
+    let err = |snippet, msg| ParseError::at(Day07::DAY, output, snippet, msg);
+    let mut tree = TreeBuilder::new()
+        .with_root(FileNode::Dir("/".to_string()))
+        .build();
+
+    let mut work_dir_id = tree.root().unwrap().node_id();
+    // Entries listed so far in each directory, by name, so lookups don't scan every entry
+    let mut entries: HashMap<NodeId, HashMap<String, NodeId>> = HashMap::new();
+    let mut lines = output.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let cmd_line = line
+            .strip_prefix("$ ")
+            .ok_or_else(|| err(line, "Expected a command starting with \"$ \""))?;
+        let mut cmd_output = Vec::new();
+        while let Some(out) = lines.next_if(|l| !l.starts_with("$ ")) {
+            cmd_output.push(out);
+        }
+
+        if let Some(cd_to) = cmd_line.strip_prefix("cd ") {
+            if let Some(out) = cmd_output.first() {
+                return Err(err(out, "Unexpected output from cd"));
+            }
+            work_dir_id = cd(&tree, &entries, work_dir_id, cd_to).map_err(|msg| err(cd_to, msg))?;
+        } else if cmd_line == "ls" {
+            for ls_out in cmd_output {
+                let (size, name) = ls_out
+                    .split_once(' ')
+                    .ok_or_else(|| err(ls_out, "Expected \"dir <name>\" or \"<size> <name>\""))?;
+                if name.contains('/') || name == "." || name == ".." {
+                    return Err(err(name, "Invalid file name"));
+                }
+                let node = if size == "dir" {
+                    FileNode::Dir(name.to_string())
+                } else {
+                    FileNode::File(parse_at(Day07::DAY, output, size)?, name.to_string())
+                };
+
+                // Listing a directory again only adds entries we haven't seen
+                let dir_entries = entries.entry(work_dir_id).or_default();
+                match dir_entries.get(name) {
+                    None => {
+                        let id = tree.get_mut(work_dir_id).unwrap().append(node).node_id();
+                        dir_entries.insert(name.to_string(), id);
+                    }
+                    Some(&seen) if *tree.get(seen).unwrap().data() == node => {}
+                    Some(_) => return Err(err(ls_out, "Conflicts with an earlier listing")),
+                }
+            }
+        } else {
+            return Err(err(cmd_line, "Unknown command"));
+        }
+    }
+    Ok(tree)
+}
+
+/// Directory reached from `from` by a relative or absolute path, like `a/e`, `../d` or `/`
+fn cd(
+    tree: &Tree<FileNode>,
+    entries: &HashMap<NodeId, HashMap<String, NodeId>>,
+    from: NodeId,
+    path: &str,
+) -> Result<NodeId, &'static str> {
+    let mut dir = match path.starts_with('/') {
+        true => tree.root().unwrap().node_id(),
+        false => from,
+    };
+    for name in path.split('/').filter(|&n| !n.is_empty() && n != ".") {
+        dir = match name {
+            ".." => tree
+                .get(dir)
+                .unwrap()
+                .parent()
+                .ok_or("Can't cd above the root directory")?
+                .node_id(),
+            _ => entries
+                .get(&dir)
+                .and_then(|e| e.get(name))
+                .copied()
+                .filter(|&id| matches!(tree.get(id).unwrap().data(), FileNode::Dir(_)))
+                .ok_or("No such directory")?,
+        };
+    }
+    Ok(dir)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileNode {
+    Dir(String),
+    File(usize, String),
+}
+
+impl FileNode {
+    pub fn name(&self) -> &str {
+        match self {
+            FileNode::Dir(name) | FileNode::File(_, name) => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_TERMINAL_OUTPUT: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_parses_terminal_output() {
+        parse_term(TEST_TERMINAL_OUTPUT).unwrap();
+    }
+
+    #[test]
+    fn it_reports_unknown_commands() {
+        let err = parse_term("$ cd /\n$ ls\ndir a\n$ cd a\n$ rm -rf b")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (5, 3, "rm -rf b"),
+            (err.line, err.column, err.snippet.as_str())
+        );
+    }
+
+    #[test]
+    fn it_parses_whole_sessions() {
+        let output = "$ ls\ndir a\n1 b\n$ cd a\n$ ls\ndir c\n$ cd c\n$ ls\n10 d\n$ cd /\n$ ls\n1 b\n2 e\n$ cd /a/c/..\n$ ls\n100 f\ndir c\n$ cd ./c\n$ ls\n10 d";
+        let fs = VirtualFs::from(parse_term(output).unwrap());
+        assert_eq!(113, fs.du("/").unwrap());
+        assert_eq!(110, fs.du("/a").unwrap());
+        assert_eq!(3, fs.ls("/").unwrap().len());
+        assert_eq!(1, fs.ls("/a/c").unwrap().len());
+    }
+
+    #[test]
+    fn it_reports_inconsistent_sessions() {
+        let err = parse_term("$ cd /\n$ ls\n1 a\n$ ls\n2 a").err().unwrap();
+        assert_eq!(
+            (5, 1, "Conflicts with an earlier listing"),
+            (err.line, err.column, err.message.as_str())
+        );
+
+        let err = parse_term("$ cd /\n$ cd b/c").err().unwrap();
+        assert_eq!((2, 6, "b/c"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = parse_term("$ cd /\ndir a").err().unwrap();
+        assert_eq!(
+            (2, 1, "Unexpected output from cd"),
+            (err.line, err.column, err.message.as_str())
+        );
+
+        let err = parse_term("ls\n").err().unwrap();
+        assert_eq!((1, 1, "ls"), (err.line, err.column, err.snippet.as_str()));
+    }
+
+    #[test]
+    fn it_passes_example_1() {
+        assert_eq!(
+            95437,
+            part_1_sum_dirs_size_lt_100_000(&Day07::parse(TEST_TERMINAL_OUTPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_passes_example_2() {
+        assert_eq!(
+            Some(("/d".to_string(), 24933642)),
+            part_2_find_dir_to_delete(&Day07::parse(TEST_TERMINAL_OUTPUT).unwrap())
+        )
+    }
+
+    #[test]
+    fn it_handles_deep_trees() {
+        let depth = 150_000;
+        let mut output = "$ cd /".to_string();
+        for _ in 0..depth {
+            output += "\n$ ls\ndir a\n1 f\n$ cd a";
+        }
+        let fs = Day07::parse(&output).unwrap();
+        assert_eq!(depth, fs.du("/").unwrap());
+        // Directory sizes go from `depth` at the root down to 0 for the deepest one
+        assert_eq!(
+            (0..100_000).sum::<usize>(),
+            part_1_sum_dirs_size_lt_100_000(&fs)
+        );
+
+        // With 1000 bytes per directory, the smallest one freeing 30M is 120_000 levels down
+        let fs = Day07::parse(&output.replace("1 f", "1000 f")).unwrap();
+        let (path, size) = part_2_find_dir_to_delete(&fs).unwrap();
+        assert_eq!(30_000_000, size);
+        assert_eq!("/a".repeat(120_000), path);
+    }
+
+    #[test]
+    fn it_handles_wide_dirs() {
+        let width = 100_000;
+        let listing = (0..width)
+            .map(|i| format!("{i} f{i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        // Listed twice, with a directory at the end to cd into
+        let output = format!("$ cd /\n$ ls\n{listing}\n$ ls\n{listing}\ndir d\n$ cd d\n$ ls\n7 g");
+        let fs = Day07::parse(&output).unwrap();
+        assert_eq!(width + 1, fs.ls("/").unwrap().len());
+        assert_eq!((0..width).sum::<usize>() + 7, fs.du("/").unwrap());
+        assert_eq!(7, fs.du("/d").unwrap());
+    }
+}
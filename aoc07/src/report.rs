@@ -0,0 +1,124 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{FileNode, VirtualFs};
+
+/// The whole filesystem in the puzzle's own format, with the size of every directory
+pub fn tree_view(fs: &VirtualFs) -> String {
+    let mut view = String::new();
+    for (depth, _, node) in fs.walk() {
+        let indent = "  ".repeat(depth);
+        let size = fs.size(node.node_id());
+        view += &match node.data() {
+            FileNode::Dir(name) => format!("{indent}- {name} (dir, size={size})\n"),
+            FileNode::File(_, name) => format!("{indent}- {name} (file, size={size})\n"),
+        };
+    }
+    view
+}
+
+/// Every directory with its human-readable size, largest first, like `du -h | sort -hr`
+pub fn du_listing(fs: &VirtualFs) -> String {
+    largest_dirs(fs, usize::MAX)
+        .into_iter()
+        .map(|(path, size)| format!("{}\t{path}\n", human_size(size)))
+        .collect()
+}
+
+/// The `n` largest directories as path and size, largest first
+pub fn largest_dirs(fs: &VirtualFs, n: usize) -> Vec<(String, usize)> {
+    let mut dirs = fs
+        .walk()
+        .filter(|(_, _, node)| matches!(node.data(), FileNode::Dir(_)))
+        .map(|(_, path, node)| (path, fs.size(node.node_id())))
+        .collect::<Vec<_>>();
+    dirs.sort_by(|(_, a), (_, b)| b.cmp(a));
+    dirs.truncate(n);
+    dirs
+}
+
+/// Total size of files per extension, largest first. Files without one are under `""`.
+pub fn size_by_extension(fs: &VirtualFs) -> Vec<(String, usize)> {
+    let mut sizes = HashMap::new();
+    for node in fs.root().traverse_pre_order() {
+        if let FileNode::File(size, name) = node.data() {
+            let extension = Path::new(name)
+                .extension()
+                .map_or(String::new(), |ext| ext.to_string_lossy().into_owned());
+            *sizes.entry(extension).or_insert(0) += size;
+        }
+    }
+    let mut sizes = sizes.into_iter().collect::<Vec<_>>();
+    sizes.sort_by(|(ext_a, a), (ext_b, b)| b.cmp(a).then(ext_a.cmp(ext_b)));
+    sizes
+}
+
+/// Size in powers of 1024 rounded up, with one decimal below 10, like `du -h`
+pub fn human_size(size: usize) -> String {
+    let mut size = size as f64;
+    for unit in ["", "K", "M", "G", "T"] {
+        if size < 1024.0 || unit == "T" {
+            return match unit {
+                "" => format!("{size}"),
+                _ if size < 10.0 => format!("{:.1}{unit}", (size * 10.0).ceil() / 10.0),
+                _ => format!("{}{unit}", size.ceil()),
+            };
+        }
+        size /= 1024.0;
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+
+    const TEST_TERMINAL_OUTPUT: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_shows_the_tree() {
+        let fs = Day07::parse(TEST_TERMINAL_OUTPUT).unwrap();
+        assert_eq!(
+            "\
+- / (dir, size=48381165)
+  - a (dir, size=94853)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir, size=24933642)
+    - j (file, size=4060174)
+    - d.log (file, size=8033020)
+    - d.ext (file, size=5626152)
+    - k (file, size=7214296)
+",
+            tree_view(&fs)
+        );
+    }
+
+    #[test]
+    fn it_lists_dirs_by_size() {
+        let fs = Day07::parse(TEST_TERMINAL_OUTPUT).unwrap();
+        assert_eq!("47M\t/\n24M\t/d\n93K\t/a\n584\t/a/e\n", du_listing(&fs));
+        assert_eq!(
+            vec![("/".to_string(), 48381165), ("/d".to_string(), 24933642)],
+            largest_dirs(&fs, 2)
+        );
+        assert_eq!("1.5K", human_size(1500));
+    }
+
+    #[test]
+    fn it_breaks_down_sizes_by_extension() {
+        let fs = Day07::parse(TEST_TERMINAL_OUTPUT).unwrap();
+        let sizes = size_by_extension(&fs);
+        assert_eq!(
+            vec!["txt", "", "dat", "log", "ext", "lst"],
+            sizes.iter().map(|(ext, _)| ext).collect::<Vec<_>>()
+        );
+        assert_eq!(29116 + 2557 + 584 + 4060174 + 7214296, sizes[1].1);
+    }
+}
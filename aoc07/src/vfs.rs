@@ -0,0 +1,248 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    iter,
+};
+
+use glob::{MatchOptions, Pattern, PatternError};
+use slab_tree::{NodeId, NodeRef, Tree};
+
+use crate::FileNode;
+
+/// The filesystem seen in the terminal output, queried by path like a real one.
+/// Paths are absolute (`/a/e`), or relative to the root, and may contain `.` and `..`.
+pub struct VirtualFs {
+    tree: Tree<FileNode>,
+    /// Size of every node, a directory's being the total of everything in it
+    sizes: HashMap<NodeId, usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stat {
+    /// Absolute path
+    pub path: String,
+    pub is_dir: bool,
+    /// Size of the file, or total size of everything in the directory
+    pub size: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FsError {
+    NotFound(String),
+    NotADirectory(String),
+}
+
+impl Display for FsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FsError::NotFound(path) => write!(f, "{path}: No such file or directory"),
+            FsError::NotADirectory(path) => write!(f, "{path}: Not a directory"),
+        }
+    }
+}
+
+impl Error for FsError {}
+
+impl From<Tree<FileNode>> for VirtualFs {
+    fn from(tree: Tree<FileNode>) -> Self {
+        // Post-order visits every child before its parent, so directory sizes are a single pass
+        let mut sizes = HashMap::new();
+        for node in tree.root().unwrap().traverse_post_order() {
+            let size = match node.data() {
+                FileNode::Dir(_) => node.children().map(|c| sizes[&c.node_id()]).sum(),
+                FileNode::File(size, _) => *size,
+            };
+            sizes.insert(node.node_id(), size);
+        }
+        VirtualFs { tree, sizes }
+    }
+}
+
+impl VirtualFs {
+    pub fn tree(&self) -> &Tree<FileNode> {
+        &self.tree
+    }
+
+    pub fn root(&self) -> NodeRef<'_, FileNode> {
+        self.tree.root().unwrap()
+    }
+
+    /// Size of a file, or total size of everything in a directory
+    pub fn size(&self, node: NodeId) -> usize {
+        self.sizes[&node]
+    }
+
+    /// Finds the node at the given path
+    pub fn resolve(&self, path: &str) -> Result<NodeId, FsError> {
+        let mut node = self.root();
+        for name in path.split('/') {
+            node = match name {
+                "" | "." => node,
+                ".." => node.parent().unwrap_or(node),
+                _ if matches!(node.data(), FileNode::File(..)) => {
+                    return Err(FsError::NotADirectory(path.to_string()))
+                }
+                _ => node
+                    .children()
+                    .find(|c| c.data().name() == name)
+                    .ok_or_else(|| FsError::NotFound(path.to_string()))?,
+            }
+        }
+        Ok(node.node_id())
+    }
+
+    /// Absolute path of a node
+    pub fn path(&self, node: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = self.tree.get(node);
+        while let Some(n) = node {
+            let parent = n.parent();
+            if parent.is_some() {
+                names.push(n.data().name());
+            }
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Every node in depth-first order, with its depth and absolute path. Each path is built
+    /// from its parent's as the walk goes down, rather than by walking back up to the root.
+    pub fn walk(&self) -> impl Iterator<Item = (usize, String, NodeRef<'_, FileNode>)> {
+        let mut stack = vec![(0, "/".to_string(), self.root())];
+        iter::from_fn(move || {
+            let (depth, path, node) = stack.pop()?;
+            let children = node.children().collect::<Vec<_>>();
+            for child in children.into_iter().rev() {
+                let name = child.data().name();
+                let child_path = match depth {
+                    0 => format!("/{name}"),
+                    _ => format!("{path}/{name}"),
+                };
+                stack.push((depth + 1, child_path, child));
+            }
+            Some((depth, path, node))
+        })
+    }
+
+    /// Absolute path of the directory containing the given path, `None` for the root
+    pub fn parent(&self, path: &str) -> Result<Option<String>, FsError> {
+        let node = self.resolve(path)?;
+        let parent = self.tree.get(node).unwrap().parent();
+        Ok(parent.map(|p| self.path(p.node_id())))
+    }
+
+    pub fn stat(&self, path: &str) -> Result<Stat, FsError> {
+        Ok(self.stat_node(self.tree.get(self.resolve(path)?).unwrap()))
+    }
+
+    /// Contents of a directory, in the order they were listed
+    pub fn ls(&self, path: &str) -> Result<Vec<Stat>, FsError> {
+        let dir = self.tree.get(self.resolve(path)?).unwrap();
+        match dir.data() {
+            FileNode::Dir(_) => Ok(dir.children().map(|c| self.stat_node(c)).collect()),
+            FileNode::File(..) => Err(FsError::NotADirectory(path.to_string())),
+        }
+    }
+
+    /// Disk usage of a file, or everything in a directory
+    pub fn du(&self, path: &str) -> Result<usize, FsError> {
+        Ok(self.size(self.resolve(path)?))
+    }
+
+    /// Absolute paths matching a glob pattern, e.g. `/**/*.txt`, in depth-first order.
+    /// `*` doesn't match `/`, use `**` to match across directories.
+    pub fn find(&self, pattern: &str) -> Result<Vec<String>, PatternError> {
+        let pattern = Pattern::new(pattern)?;
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        Ok(self
+            .walk()
+            .map(|(_, path, _)| path)
+            .filter(|path| pattern.matches_with(path, options))
+            .collect())
+    }
+
+    fn stat_node(&self, node: NodeRef<FileNode>) -> Stat {
+        Stat {
+            path: self.path(node.node_id()),
+            is_dir: matches!(node.data(), FileNode::Dir(_)),
+            size: self.size(node.node_id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_term;
+
+    const TEST_TERMINAL_OUTPUT: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_queries_paths() {
+        let fs = VirtualFs::from(parse_term(TEST_TERMINAL_OUTPUT).unwrap());
+        assert_eq!(
+            Stat {
+                path: "/a/e".to_string(),
+                is_dir: true,
+                size: 584,
+            },
+            fs.stat("/a/e").unwrap()
+        );
+        assert_eq!(94853, fs.du("a").unwrap());
+        assert_eq!(48381165, fs.du("/").unwrap());
+        assert_eq!(
+            vec!["/d/j", "/d/d.log", "/d/d.ext", "/d/k"],
+            fs.ls("/d")
+                .unwrap()
+                .into_iter()
+                .map(|s| s.path)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("/a", fs.path(fs.resolve("/a/e/../../d/./../a").unwrap()));
+        assert_eq!(Some("/a".to_string()), fs.parent("/a/e").unwrap());
+        assert_eq!(None, fs.parent("/").unwrap());
+    }
+
+    #[test]
+    fn it_walks_the_tree() {
+        let fs = VirtualFs::from(parse_term(TEST_TERMINAL_OUTPUT).unwrap());
+        let walked = fs.walk().collect::<Vec<_>>();
+        let pre_order = fs.root().traverse_pre_order().collect::<Vec<_>>();
+        assert_eq!(pre_order.len(), walked.len());
+        for ((depth, path, node), expected) in walked.iter().zip(&pre_order) {
+            assert_eq!(expected.node_id(), node.node_id());
+            assert_eq!(fs.path(node.node_id()), *path);
+            assert_eq!(*depth, path.split('/').filter(|n| !n.is_empty()).count());
+        }
+    }
+
+    #[test]
+    fn it_finds_globs() {
+        let fs = VirtualFs::from(parse_term(TEST_TERMINAL_OUTPUT).unwrap());
+        assert_eq!(vec!["/d/d.log"], fs.find("/*/*.log").unwrap());
+        assert_eq!(vec!["/a/e/i"], fs.find("/**/i").unwrap());
+        assert_eq!(vec!["/a/e", "/a/f", "/a/g"], fs.find("/a/?").unwrap());
+    }
+
+    #[test]
+    fn it_reports_bad_paths() {
+        let fs = VirtualFs::from(parse_term(TEST_TERMINAL_OUTPUT).unwrap());
+        assert_eq!(
+            FsError::NotFound("/a/x".to_string()),
+            fs.stat("/a/x").unwrap_err()
+        );
+        assert_eq!(
+            FsError::NotADirectory("/b.txt/x".to_string()),
+            fs.ls("/b.txt/x").unwrap_err()
+        );
+        assert_eq!(
+            FsError::NotADirectory("/b.txt".to_string()),
+            fs.ls("/b.txt").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(isize),
+    Addy(isize),
+    Mulx(isize),
+    /// Relative jump, `jmp 1` continues with the next instruction
+    Jmp(isize),
+}
+
+impl Instruction {
+    /// Number of CPU cycles the instruction takes to complete
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+            Instruction::Addy(_) => 2,
+            Instruction::Mulx(_) => 3,
+            Instruction::Jmp(_) => 1,
+        }
+    }
+
+    /// Applies the instruction to the registers, returning the offset to the next instruction.
+    /// Registers wrap around on overflow.
+    fn execute(&self, registers: &mut Registers) -> isize {
+        match *self {
+            Instruction::Noop => {}
+            Instruction::Addx(arg) => registers.x = registers.x.wrapping_add(arg),
+            Instruction::Addy(arg) => registers.y = registers.y.wrapping_add(arg),
+            Instruction::Mulx(arg) => registers.x = registers.x.wrapping_mul(arg),
+            Instruction::Jmp(offset) => return offset,
+        }
+        1
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Registers { x: 1, y: 0 }
+    }
+}
+
+/// Register values during a single CPU cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Cycle number, starting at 1
+    pub number: usize,
+    pub registers: Registers,
+}
+
+impl Cycle {
+    /// Cycle number times X, wrapping around on overflow like the registers
+    pub fn signal_strength(&self) -> isize {
+        (self.number as isize).wrapping_mul(self.registers.x)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    registers: Registers,
+    /// Index of the instruction being executed
+    pc: usize,
+    /// Completed cycles
+    cycle: usize,
+    /// Cycles spent so far on the current instruction
+    elapsed: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Cpu {
+            program,
+            registers: Registers::default(),
+            pc: 0,
+            cycle: 0,
+            elapsed: 0,
+        }
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    /// Index of the instruction being executed
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Number of completed cycles
+    pub fn completed_cycles(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Runs a single clock cycle, returning the register values during the cycle,
+    /// or `None` if the program has ended
+    pub fn step(&mut self) -> Option<Cycle> {
+        let instruction = self.program.get(self.pc)?;
+        self.cycle += 1;
+        let during = Cycle {
+            number: self.cycle,
+            registers: self.registers,
+        };
+
+        self.elapsed += 1;
+        if self.elapsed == instruction.cycles() {
+            self.elapsed = 0;
+            let offset = instruction.execute(&mut self.registers);
+            // Jumping out of the program in either direction halts it
+            self.pc = self.pc.checked_add_signed(offset).unwrap_or(usize::MAX);
+        }
+        Some(during)
+    }
+
+    /// Runs the program until it ends, returning the final register values.
+    /// Programs with backward jumps may never end.
+    pub fn run(&mut self) -> Registers {
+        while self.step().is_some() {}
+        self.registers
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = Cycle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
+}
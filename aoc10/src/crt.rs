@@ -0,0 +1,141 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Cpu, Instruction};
+
+pub const CRT_WIDTH: usize = 40;
+pub const CRT_HEIGHT: usize = 6;
+
+/// Size of the display, the sprite drawn on it and when the signal strength is sampled
+/// A width or height of zero gives a screen without pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrtConfig {
+    pub width: usize,
+    pub height: usize,
+    /// Width of the sprite, centered on X (or one pixel right of center for even widths)
+    pub sprite_width: usize,
+    /// Cycle numbers during which the signal strength is sampled
+    pub sample_cycles: Vec<usize>,
+}
+
+impl Default for CrtConfig {
+    fn default() -> Self {
+        CrtConfig {
+            width: CRT_WIDTH,
+            height: CRT_HEIGHT,
+            sprite_width: 3,
+            sample_cycles: vec![20, 60, 100, 140, 180, 220],
+        }
+    }
+}
+
+impl CrtConfig {
+    /// Whether the sprite, positioned by `x_register`, covers the given column
+    pub fn sprite_covers(&self, x_register: isize, column: usize) -> bool {
+        // Any `usize` and `isize` fits in an `i128`, so huge sprites can't overflow
+        let half = (self.sprite_width.saturating_sub(1) / 2) as i128;
+        let left = x_register as i128 - half;
+        let sprite = left..left + self.sprite_width as i128;
+        sprite.contains(&(column as i128))
+    }
+}
+
+/// Grid of lit/dark pixels, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Framebuffer {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Framebuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Framebuffer {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the pixel at column `x` of row `y` is lit
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[y * self.width + x] = lit;
+    }
+
+    /// Every row from the top, empty ones too when the width is zero
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        (0..self.height).map(|y| &self.pixels[y * self.width..(y + 1) * self.width])
+    }
+}
+
+impl Display for Framebuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { '#' } else { '-' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A pixel drawn by the beam during a CPU cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pixel {
+    pub x: usize,
+    pub y: usize,
+    pub lit: bool,
+}
+
+/// The pixels drawn by the beam, one per CPU cycle row by row, lit when the sprite covers them
+pub fn pixels<'a>(
+    program: &'a [Instruction],
+    config: &'a CrtConfig,
+) -> impl Iterator<Item = Pixel> + 'a {
+    // Without pixels there's nothing to take, so the width is never zero below
+    Cpu::new(program)
+        .take(config.width * config.height)
+        .enumerate()
+        .map(|(i, cycle)| {
+            let x = i % config.width;
+            Pixel {
+                x,
+                y: i / config.width,
+                lit: config.sprite_covers(cycle.registers.x, x),
+            }
+        })
+}
+
+/// The screen once every pixel has been drawn
+pub fn render(program: &[Instruction], config: &CrtConfig) -> Framebuffer {
+    let mut frame = Framebuffer::new(config.width, config.height);
+    for pixel in pixels(program, config) {
+        frame.set(pixel.x, pixel.y, pixel.lit);
+    }
+    frame
+}
+
+/// Sum of cycle number times X, during each of the sample cycles. Wraps around on overflow.
+pub fn sum_signal_strengths(program: &[Instruction], config: &CrtConfig) -> isize {
+    let last = config.sample_cycles.iter().copied().max().unwrap_or(0);
+    Cpu::new(program)
+        .take(last)
+        .filter(|cycle| config.sample_cycles.contains(&cycle.number))
+        .fold(0, |sum, cycle| sum.wrapping_add(cycle.signal_strength()))
+}
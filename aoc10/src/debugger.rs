@@ -0,0 +1,494 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{Cpu, CrtConfig, Cycle, Instruction};
+
+/// `step` and `continue` give up after this many cycles, programs with backward jumps may
+/// never end
+const MAX_CONTINUE_CYCLES: usize = 1_000_000;
+
+const HELP: &str = "\
+s, step [n]         run n cycles (default 1)
+b, back [n]         go back n cycles (default 1)
+c, continue         run until a breakpoint is hit or the program ends
+rc, rcontinue       go back until a breakpoint is hit or the first cycle
+break cycle <n>     stop at cycle n
+break x <n>         stop when X changes to n
+delete <n>          remove breakpoint n
+watch <expr>        print an expression after every command, e.g. `cycle * x`
+unwatch <n>         remove watch n
+info                list breakpoints and watches
+p, print            show the current cycle
+q, quit             exit the debugger
+Expressions combine numbers and cycle, x, y, pc, signal with + - * /";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    Cycle(usize),
+    /// Hit when X changes to the value
+    X(isize),
+}
+
+/// Steps through a program one cycle at a time, in either direction. Every cycle run is kept,
+/// so going back and forward again doesn't run the CPU again.
+#[derive(Clone, Debug)]
+pub struct Debugger<'a> {
+    program: &'a [Instruction],
+    crt: CrtConfig,
+    cpu: Cpu<'a>,
+    /// Cycles run so far, with the index of the instruction executing during each one
+    history: Vec<(Cycle, usize)>,
+    /// Number of the cycle being inspected, 0 only for an empty program
+    current: usize,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<String>,
+}
+
+impl<'a> Debugger<'a> {
+    /// Starts the program, stopping at its first cycle
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Self::with_config(program, CrtConfig::default())
+    }
+
+    /// Starts the program on a CRT of a different size or sprite width
+    pub fn with_config(program: &'a [Instruction], crt: CrtConfig) -> Self {
+        let mut debugger = Debugger {
+            program,
+            crt,
+            cpu: Cpu::new(program),
+            history: Vec::new(),
+            current: 0,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+        };
+        debugger.forward();
+        debugger
+    }
+
+    /// The cycle being inspected
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.history
+            .get(self.current.checked_sub(1)?)
+            .map(|(c, _)| *c)
+    }
+
+    /// Runs up to `n` cycles, stopping early if the program ends or after
+    /// `MAX_CONTINUE_CYCLES`. Returns the number of cycles run.
+    pub fn step(&mut self, n: usize) -> usize {
+        (0..n.min(MAX_CONTINUE_CYCLES))
+            .take_while(|_| self.forward())
+            .count()
+    }
+
+    /// Goes back up to `n` cycles, returning the number of cycles gone back
+    pub fn step_back(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.back()).count()
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    /// Runs until a breakpoint is hit, returning it, or until the program ends
+    pub fn continue_forward(&mut self) -> Option<Breakpoint> {
+        for _ in 0..MAX_CONTINUE_CYCLES {
+            if !self.forward() {
+                return None;
+            }
+            if let Some(hit) = self.breakpoint_hit() {
+                return Some(hit);
+            }
+        }
+        None
+    }
+
+    /// Goes back until a breakpoint is hit, returning it, or until the first cycle
+    pub fn continue_back(&mut self) -> Option<Breakpoint> {
+        while self.back() {
+            if let Some(hit) = self.breakpoint_hit() {
+                return Some(hit);
+            }
+        }
+        None
+    }
+
+    /// Evaluates an expression for the cycle being inspected
+    pub fn evaluate(&self, expression: &str) -> Result<isize, String> {
+        let (cycle, pc) = self
+            .current
+            .checked_sub(1)
+            .map(|i| self.history[i])
+            .ok_or("The program is empty")?;
+        let number = isize::try_from(cycle.number).unwrap();
+        evaluate(expression, |name| match name {
+            "cycle" => Some(number),
+            "x" => Some(cycle.registers.x),
+            "y" => Some(cycle.registers.y),
+            "pc" => Some(isize::try_from(pc).unwrap()),
+            "signal" => Some(cycle.signal_strength()),
+            _ => None,
+        })
+    }
+
+    /// Runs a debugger command, returning what to show the user
+    pub fn execute(&mut self, command: &str) -> Result<String, String> {
+        let words = command.split_whitespace().collect::<Vec<_>>();
+        let count =
+            |arg: Option<&&str>| arg.map_or(Ok(1), |n| n.parse().map_err(|_| "Expected a count"));
+        let message = match words.as_slice() {
+            ["s" | "step", rest @ ..] => {
+                let n = count(rest.first())?;
+                match self.step(n) {
+                    ran if ran == n => None,
+                    _ if self.is_at_end() => Some("Program ended".to_string()),
+                    ran => Some(format!("Stopped after {ran} cycles")),
+                }
+            }
+            ["b" | "back", rest @ ..] => {
+                let n = count(rest.first())?;
+                (self.step_back(n) < n).then(|| "At the first cycle".to_string())
+            }
+            ["c" | "continue"] => Some(match self.continue_forward() {
+                Some(hit) => format!("Hit breakpoint {hit:?}"),
+                None if self.is_at_end() => "Program ended".to_string(),
+                None => format!("No breakpoint hit in {MAX_CONTINUE_CYCLES} cycles"),
+            }),
+            ["rc" | "rcontinue"] => Some(match self.continue_back() {
+                Some(hit) => format!("Hit breakpoint {hit:?}"),
+                None => "At the first cycle".to_string(),
+            }),
+            ["break", "cycle", n] => {
+                let n = n.parse().map_err(|_| "Expected a cycle number")?;
+                self.add_breakpoint(Breakpoint::Cycle(n));
+                return Ok(format!("Breakpoint {}: cycle {n}", self.breakpoints.len()));
+            }
+            ["break", "x", n] => {
+                let n = n.parse().map_err(|_| "Expected a value of X")?;
+                self.add_breakpoint(Breakpoint::X(n));
+                return Ok(format!("Breakpoint {}: X = {n}", self.breakpoints.len()));
+            }
+            ["delete", n] => {
+                let n = remove(&mut self.breakpoints, n)?;
+                return Ok(format!("Deleted breakpoint {n:?}"));
+            }
+            ["watch", ..] => {
+                let expression = command.trim_start()["watch".len()..].trim().to_string();
+                self.evaluate(&expression)?;
+                self.watches.push(expression);
+                None
+            }
+            ["unwatch", n] => {
+                let n = remove(&mut self.watches, n)?;
+                return Ok(format!("Removed watch {n}"));
+            }
+            ["info"] => return Ok(self.info()),
+            ["p" | "print"] => None,
+            ["help"] => return Ok(HELP.to_string()),
+            _ => return Err(format!("Unknown command {command:?}, try \"help\"")),
+        };
+        Ok(match message {
+            Some(message) => format!("{message}\n{}", self.view()),
+            None => self.view(),
+        })
+    }
+
+    /// The cycle being inspected, the CRT row drawn so far, the sprite position and all watches
+    pub fn view(&self) -> String {
+        let Some((cycle, pc)) = self.current.checked_sub(1).map(|i| self.history[i]) else {
+            return "The program is empty".to_string();
+        };
+        let mut lines = vec![format!(
+            "cycle {}: {:?} (pc {pc}), x = {}, y = {}",
+            cycle.number, self.program[pc], cycle.registers.x, cycle.registers.y
+        )];
+        // A CRT without columns has nothing to draw
+        let width = self.crt.width;
+        if let Some(row_number) = (cycle.number - 1).checked_div(width) {
+            let row_start = row_number * width;
+            let column = cycle.number - 1 - row_start;
+            let row = self.history[row_start..cycle.number]
+                .iter()
+                .enumerate()
+                .map(
+                    |(col, (c, _))| match self.crt.sprite_covers(c.registers.x, col) {
+                        true => '#',
+                        false => '-',
+                    },
+                )
+                .collect::<String>();
+            let sprite = (0..width)
+                .map(|col| match self.crt.sprite_covers(cycle.registers.x, col) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect::<String>();
+            lines.push(format!("crt row {row_number}: {row}"));
+            lines.push(format!("sprite:    {sprite}"));
+            lines.push(format!("           {}^", " ".repeat(column)));
+        }
+        for (i, watch) in self.watches.iter().enumerate() {
+            lines.push(match self.evaluate(watch) {
+                Ok(value) => format!("watch {}: {watch} = {value}", i + 1),
+                Err(e) => format!("watch {}: {watch}: {e}", i + 1),
+            });
+        }
+        lines.join("\n")
+    }
+
+    fn info(&self) -> String {
+        let breakpoints = self
+            .breakpoints
+            .iter()
+            .enumerate()
+            .map(|(i, b)| format!("breakpoint {}: {b:?}", i + 1));
+        let watches = self
+            .watches
+            .iter()
+            .enumerate()
+            .map(|(i, w)| format!("watch {}: {w}", i + 1));
+        breakpoints.chain(watches).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Whether the last cycle of the program is being inspected
+    fn is_at_end(&self) -> bool {
+        self.cpu.is_halted() && self.current == self.history.len()
+    }
+
+    fn forward(&mut self) -> bool {
+        if self.current < self.history.len() {
+            self.current += 1;
+            return true;
+        }
+        let pc = self.cpu.pc();
+        match self.cpu.step() {
+            Some(cycle) => {
+                self.history.push((cycle, pc));
+                self.current += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn back(&mut self) -> bool {
+        if self.current > 1 {
+            self.current -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn breakpoint_hit(&self) -> Option<Breakpoint> {
+        let (cycle, _) = self.history[self.current - 1];
+        let previous_x = self
+            .current
+            .checked_sub(2)
+            .map(|i| self.history[i].0.registers.x);
+        self.breakpoints.iter().copied().find(|b| match *b {
+            Breakpoint::Cycle(n) => cycle.number == n,
+            Breakpoint::X(x) => cycle.registers.x == x && previous_x != Some(x),
+        })
+    }
+}
+
+/// Runs the debugger on commands read line by line, until the input ends or the user quits
+pub fn repl(
+    program: &[Instruction],
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut debugger = Debugger::new(program);
+    writeln!(output, "{}", debugger.view())?;
+    write!(output, "(debug) ")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        match line.trim() {
+            "q" | "quit" => break,
+            "" => {}
+            command => match debugger.execute(command) {
+                Ok(shown) => writeln!(output, "{shown}")?,
+                Err(e) => writeln!(output, "error: {e}")?,
+            },
+        }
+        write!(output, "(debug) ")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+/// Removes the item with the given 1-based number from the list
+fn remove<T>(items: &mut Vec<T>, n: &str) -> Result<T, String> {
+    match n.parse::<usize>() {
+        Ok(n) if (1..=items.len()).contains(&n) => Ok(items.remove(n - 1)),
+        _ => Err(format!("No item {n:?}")),
+    }
+}
+
+/// Evaluates a simple arithmetic expression of numbers and variables, e.g. `cycle * x + 1`,
+/// where `*` and `/` bind tighter than `+` and `-`
+fn evaluate(expression: &str, var: impl Fn(&str) -> Option<isize>) -> Result<isize, String> {
+    let mut values = Vec::new();
+    let mut ops = Vec::new();
+    let mut chars = expression.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if "+-*/".contains(c) {
+            ops.push(c);
+            chars.next();
+        } else if c.is_ascii_alphanumeric() {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !c.is_ascii_alphanumeric() {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            let token = &expression[start..end];
+            values.push(match token.parse::<isize>() {
+                Ok(n) => n,
+                Err(_) => var(token).ok_or_else(|| format!("Unknown variable {token:?}"))?,
+            });
+        } else {
+            return Err(format!("Unexpected {c:?} in expression"));
+        }
+        if values.len() != ops.len() && values.len() != ops.len() + 1 {
+            return Err(format!("Expected a value before {c:?}"));
+        }
+    }
+    if values.is_empty() || values.len() != ops.len() + 1 {
+        return Err("Incomplete expression".to_string());
+    }
+
+    let overflow = || "Overflow".to_string();
+    let (mut sum, mut product) = (0isize, values[0]);
+    for (op, &value) in ops.iter().zip(&values[1..]) {
+        (sum, product) = match op {
+            '*' => (sum, product.checked_mul(value).ok_or_else(overflow)?),
+            '/' if value == 0 => return Err("Division by zero".to_string()),
+            '/' => (sum, product.checked_div(value).ok_or_else(overflow)?),
+            '+' => (sum.checked_add(product).ok_or_else(overflow)?, value),
+            _ => (
+                sum.checked_add(product).ok_or_else(overflow)?,
+                value.checked_neg().ok_or_else(overflow)?,
+            ),
+        };
+    }
+    sum.checked_add(product).ok_or_else(overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+
+    const PROGRAM: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_steps_both_ways() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let mut debugger = Debugger::new(&program);
+        assert_eq!(19, debugger.step(19));
+        assert_eq!(Ok(420), debugger.evaluate("signal"));
+        assert_eq!(Ok(21), debugger.evaluate("x"));
+
+        assert_eq!(19, debugger.step_back(100));
+        assert_eq!(1, debugger.cycle().unwrap().number);
+        assert_eq!(Ok(16), debugger.evaluate("x * 3 - pc / 2 + 13"));
+    }
+
+    #[test]
+    fn it_stops_at_breakpoints() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let mut debugger = Debugger::new(&program);
+        debugger.execute("break cycle 60").unwrap();
+        debugger.execute("break x 16").unwrap();
+        debugger.execute("watch cycle*x").unwrap();
+
+        let shown = debugger.execute("c").unwrap();
+        assert!(shown.starts_with("Hit breakpoint X(16)\ncycle 3: Addx(-11)"));
+        assert_eq!(Some(Breakpoint::Cycle(60)), debugger.continue_forward());
+        assert!(debugger.view().ends_with("watch 1: cycle*x = 1140"));
+
+        assert_eq!(Some(Breakpoint::X(16)), debugger.continue_back());
+        assert_eq!(3, debugger.cycle().unwrap().number);
+    }
+
+    #[test]
+    fn it_shows_the_crt_row() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let mut debugger = Debugger::new(&program);
+        debugger.step(42);
+        let view = debugger.view();
+        let lines = view.lines().collect::<Vec<_>>();
+        assert_eq!("crt row 1: ###", lines[1]);
+        assert_eq!(
+            "sprite:    .###....................................",
+            lines[2]
+        );
+        assert_eq!("             ^", lines[3]);
+    }
+
+    #[test]
+    fn it_runs_commands_until_quit() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let input = io::Cursor::new("s 19\n\nbreak x 16\nq\nwatch x\n");
+        let mut output = Vec::new();
+        repl(&program, input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let sessions = output.split("(debug) ").collect::<Vec<_>>();
+        assert_eq!(5, sessions.len());
+        assert!(sessions[0].starts_with("cycle 1: Addx(15) (pc 0), x = 1, y = 0\n"));
+        assert!(sessions[1].starts_with("cycle 20: Addx(-1) (pc 10), x = 21, y = 0\n"));
+        assert_eq!("", sessions[2]);
+        assert_eq!("Breakpoint 1: X = 16\n", sessions[3]);
+        // Nothing is read after quitting
+        assert_eq!("", sessions[4]);
+    }
+
+    #[test]
+    fn it_works_without_a_crt() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let config = CrtConfig {
+            width: 0,
+            ..CrtConfig::default()
+        };
+        let mut debugger = Debugger::with_config(&program, config);
+        debugger.execute("watch x").unwrap();
+        assert_eq!(
+            "cycle 2: Addx(15) (pc 0), x = 1, y = 0\nwatch 1: x = 1",
+            debugger.execute("s").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_reports_bad_commands() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let mut debugger = Debugger::new(&program);
+        assert!(debugger.execute("jump 3").is_err());
+        assert!(debugger.execute("watch z + 1").is_err());
+        assert!(debugger.execute("watch x +").is_err());
+        assert!(debugger.execute("delete 1").is_err());
+        assert_eq!(
+            Err("Overflow".to_string()),
+            debugger.execute("watch x * 9223372036854775807 * 2")
+        );
+        assert_eq!(
+            Err("Division by zero".to_string()),
+            debugger.evaluate("x / 0")
+        );
+    }
+
+    #[test]
+    fn it_limits_steps_in_endless_programs() {
+        let program = Day10::parse("addx 1\njmp -1").unwrap();
+        let mut debugger = Debugger::new(&program);
+        let shown = debugger.execute("s 1000000000000").unwrap();
+        assert!(shown.starts_with(&format!("Stopped after {MAX_CONTINUE_CYCLES} cycles")));
+        assert_eq!(MAX_CONTINUE_CYCLES + 1, debugger.cycle().unwrap().number);
+    }
+}
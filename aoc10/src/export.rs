@@ -0,0 +1,98 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    iter,
+    path::Path,
+};
+
+use crate::{pixels, CrtConfig, Framebuffer, Instruction};
+
+const LIT: [u8; 3] = [255, 204, 0];
+const DARK: [u8; 3] = [15, 15, 35];
+
+/// Writes the framebuffer as a binary PPM image, each pixel drawn as a `scale` × `scale` square
+pub fn write_ppm(frame: &Framebuffer, scale: usize, mut out: impl Write) -> io::Result<()> {
+    write!(
+        out,
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )?;
+    for row in frame.rows() {
+        let line = row
+            .iter()
+            .flat_map(|&lit| iter::repeat_n(if lit { LIT } else { DARK }, scale))
+            .flatten()
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+/// The CRT after each cycle, as the beam draws one more pixel
+pub fn frames<'a>(
+    program: &'a [Instruction],
+    config: &'a CrtConfig,
+) -> impl Iterator<Item = Framebuffer> + 'a {
+    let mut frame = Framebuffer::new(config.width, config.height);
+    pixels(program, config).map(move |pixel| {
+        frame.set(pixel.x, pixel.y, pixel.lit);
+        frame.clone()
+    })
+}
+
+/// Writes every frame to `dir` as `frame_0001.ppm`, `frame_0002.ppm` and so on,
+/// returning the number of frames written. Each frame is written as soon as it's drawn.
+pub fn write_frames(
+    program: &[Instruction],
+    config: &CrtConfig,
+    scale: usize,
+    dir: &Path,
+) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut frame = Framebuffer::new(config.width, config.height);
+    let mut count = 0;
+    for pixel in pixels(program, config) {
+        frame.set(pixel.x, pixel.y, pixel.lit);
+        count += 1;
+        let file = File::create(dir.join(format!("frame_{count:04}.ppm")))?;
+        let mut out = BufWriter::new(file);
+        write_ppm(&frame, scale, &mut out)?;
+        out.flush()?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{render, Day10};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_writes_ppm() {
+        let mut frame = Framebuffer::new(2, 1);
+        frame.set(1, 0, true);
+        let mut ppm = Vec::new();
+        write_ppm(&frame, 2, &mut ppm).unwrap();
+
+        let (header, pixels) = ppm.split_at(b"P6\n4 2\n255\n".len());
+        assert_eq!(b"P6\n4 2\n255\n", header);
+        let row = [DARK, DARK, LIT, LIT].concat();
+        assert_eq!([row.clone(), row].concat(), pixels);
+    }
+
+    #[test]
+    fn it_draws_frames_cycle_by_cycle() {
+        let program = Day10::parse(include_str!("../example.txt")).unwrap();
+        let config = CrtConfig::default();
+        let frames = frames(&program, &config).collect::<Vec<_>>();
+        let screen = render(&program, &config);
+        assert_eq!(240, frames.len());
+        assert_eq!(screen.rows().next(), frames[40].rows().next());
+        assert!(frames[40].get(0, 1) && !frames[40].get(1, 1) && screen.get(1, 1));
+        assert_eq!(&screen, frames.last().unwrap());
+    }
+}
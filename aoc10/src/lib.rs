@@ -0,0 +1,227 @@
+use std::error::Error;
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+mod cpu;
+mod crt;
+mod debugger;
+mod export;
+mod ocr;
+
+pub use cpu::{Cpu, Cycle, Instruction, Registers};
+pub use crt::{
+    pixels, render, sum_signal_strengths, CrtConfig, Framebuffer, Pixel, CRT_HEIGHT, CRT_WIDTH,
+};
+pub use debugger::{repl, Breakpoint, Debugger};
+pub use export::{frames, write_frames, write_ppm};
+pub use ocr::{decode, OcrError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = isize;
+    type Answer2 = String;
+
+    fn parse(program: &str) -> Result<Self::Input, ParseError> {
+        program
+            .lines()
+            .map(|instr| parse_instruction(program, instr))
+            .collect()
+    }
+
+    fn part1(program: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_sum_signal_strengths(program))
+    }
+
+    fn part2(program: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(decode(&part_2_draw_crt(program))?)
+    }
+}
+
+fn parse_instruction(program: &str, instr: &str) -> Result<Instruction, ParseError> {
+    match instr.split_once(' ') {
+        None if instr == "noop" => Ok(Instruction::Noop),
+        Some(("addx", arg)) => Ok(Instruction::Addx(parse_at(Day10::DAY, program, arg)?)),
+        Some(("addy", arg)) => Ok(Instruction::Addy(parse_at(Day10::DAY, program, arg)?)),
+        Some(("mulx", arg)) => Ok(Instruction::Mulx(parse_at(Day10::DAY, program, arg)?)),
+        Some(("jmp", arg)) => Ok(Instruction::Jmp(parse_at(Day10::DAY, program, arg)?)),
+        _ => Err(ParseError::at(
+            Day10::DAY,
+            program,
+            instr,
+            "Unknown instruction",
+        )),
+    }
+}
+
+pub fn part_1_sum_signal_strengths(program: &[Instruction]) -> isize {
+    sum_signal_strengths(program, &CrtConfig::default())
+}
+
+pub fn part_2_draw_crt(program: &[Instruction]) -> Framebuffer {
+    render(program, &CrtConfig::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works_simple_example() {
+        let program = Day10::parse("noop\naddx 3\naddx -5").unwrap();
+        let mut cpu = Cpu::new(&program);
+        let cycles = cpu
+            .by_ref()
+            .map(|cycle| cycle.registers.x)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 1, 1, 4, 4], cycles);
+        assert_eq!(-1, cpu.registers().x)
+    }
+
+    #[test]
+    fn it_runs_extended_instructions() {
+        let program = Day10::parse("addy 2\njmp 2\naddx 100\nmulx 3\njmp -10").unwrap();
+        let mut cpu = Cpu::new(&program);
+        assert_eq!(Registers { x: 3, y: 2 }, cpu.run());
+        assert_eq!(7, cpu.completed_cycles());
+        assert!(cpu.is_halted());
+    }
+
+    #[test]
+    fn it_wraps_registers_around() {
+        let program = Day10::parse(
+            "mulx 10000000000
+mulx 10000000000
+addy -1",
+        )
+        .unwrap();
+        let registers = Cpu::new(&program).run();
+        assert_eq!(
+            10_000_000_000isize.wrapping_mul(10_000_000_000),
+            registers.x
+        );
+        assert_eq!(-1, registers.y);
+
+        let config = CrtConfig {
+            sample_cycles: vec![7],
+            ..CrtConfig::default()
+        };
+        let strength = registers.x.wrapping_mul(7);
+        assert_eq!(strength, sum_signal_strengths(&program, &config));
+        render(&program, &config);
+    }
+
+    const PROGRAM: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_invalid_instructions() {
+        let err = Day10::parse("noop\naddx 3\naddx five").unwrap_err();
+        assert_eq!((3, 6, "five"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = Day10::parse("noop\nsubx 3").unwrap_err();
+        assert_eq!(
+            (2, 1, "subx 3"),
+            (err.line, err.column, err.snippet.as_str())
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        assert_eq!(
+            13140,
+            part_1_sum_signal_strengths(&Day10::parse(PROGRAM).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        let crt = part_2_draw_crt(&Day10::parse(PROGRAM).unwrap());
+        assert_eq!((CRT_WIDTH, CRT_HEIGHT), (crt.width(), crt.height()));
+        assert!(crt.get(0, 0) && crt.get(1, 0) && !crt.get(2, 0));
+        assert!(crt.get(39, 4) && !crt.get(39, 5));
+        assert_eq!(
+            "##--##--##--##--##--##--##--##--##--##--
+###---###---###---###---###---###---###-
+####----####----####----####----####----
+#####-----#####-----#####-----#####-----
+######------######------######------####
+#######-------#######-------#######-----",
+            crt.to_string()
+        );
+    }
+
+    #[test]
+    fn it_works_with_custom_crt() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let config = CrtConfig {
+            width: 20,
+            height: 3,
+            sprite_width: 1,
+            sample_cycles: vec![1, 3, 21],
+        };
+        assert_eq!(
+            1 + 3 * 16 + 21 * 21,
+            sum_signal_strengths(&program, &config)
+        );
+        assert_eq!(
+            "-#---#--#---#----#--
+--------------------
+--#-----#----#-----#",
+            render(&program, &config).to_string()
+        );
+
+        let config = CrtConfig {
+            width: 10,
+            height: 2,
+            sprite_width: 4,
+            sample_cycles: vec![],
+        };
+        assert_eq!(0, sum_signal_strengths(&program, &config));
+        assert_eq!(
+            "##--##--##\n----##----",
+            render(&program, &config).to_string()
+        );
+    }
+
+    #[test]
+    fn it_handles_empty_crts() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        for (width, height) in [(0, 6), (40, 0), (0, 0)] {
+            let config = CrtConfig {
+                width,
+                height,
+                ..CrtConfig::default()
+            };
+            let screen = render(&program, &config);
+            assert_eq!("\n".repeat(height.saturating_sub(1)), screen.to_string());
+            assert!(frames(&program, &config).next().is_none());
+            let mut ppm = Vec::new();
+            write_ppm(&screen, 2, &mut ppm).unwrap();
+            assert_eq!(
+                format!("P6\n{} {}\n255\n", width * 2, height * 2).as_bytes(),
+                ppm
+            );
+        }
+    }
+
+    #[test]
+    fn it_handles_huge_sprites() {
+        let program = Day10::parse(PROGRAM).unwrap();
+        let config = CrtConfig {
+            sprite_width: usize::MAX,
+            ..CrtConfig::default()
+        };
+        assert!(config.sprite_covers(isize::MAX, 0));
+        assert!(config.sprite_covers(0, usize::MAX / 2));
+        assert!(!config.sprite_covers(isize::MIN, usize::MAX));
+        let screen = render(&program, &config);
+        assert_eq!(
+            CRT_WIDTH * CRT_HEIGHT,
+            screen.to_string().matches('#').count()
+        );
+    }
+}
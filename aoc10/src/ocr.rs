@@ -0,0 +1,129 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::Framebuffer;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by a single dark column
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    WrongHeight(usize),
+    /// Glyph number `index` (from the left) isn't a known letter
+    UnknownGlyph {
+        index: usize,
+        glyph: String,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::WrongHeight(height) => write!(
+                f,
+                "Expected letters {GLYPH_HEIGHT} pixels high, got {height}"
+            ),
+            OcrError::UnknownGlyph { index, glyph } => {
+                write!(f, "Unknown glyph at letter {}:\n{glyph}", index + 1)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the capital letters drawn on the CRT
+pub fn decode(frame: &Framebuffer) -> Result<String, OcrError> {
+    if frame.height() != GLYPH_HEIGHT {
+        return Err(OcrError::WrongHeight(frame.height()));
+    }
+
+    (0..frame.width().div_ceil(GLYPH_SPACING))
+        .map(|index| {
+            let left = index * GLYPH_SPACING;
+            let glyph = (0..GLYPH_HEIGHT)
+                .map(|y| {
+                    (left..left + GLYPH_WIDTH)
+                        .map(|x| match x < frame.width() && frame.get(x, y) {
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+
+            GLYPHS
+                .iter()
+                .find(|(_, rows)| rows.iter().eq(glyph.iter()))
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    index,
+                    glyph: glyph.join("\n"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(letters: &str) -> Framebuffer {
+        let mut frame = Framebuffer::new(letters.len() * GLYPH_SPACING, GLYPH_HEIGHT);
+        for (i, letter) in letters.chars().enumerate() {
+            let (_, rows) = GLYPHS.iter().find(|(c, _)| *c == letter).unwrap();
+            for (y, row) in rows.iter().enumerate() {
+                for (x, pixel) in row.chars().enumerate() {
+                    frame.set(i * GLYPH_SPACING + x, y, pixel == '#');
+                }
+            }
+        }
+        frame
+    }
+
+    #[test]
+    fn it_decodes_letters() {
+        let letters = GLYPHS.iter().map(|(c, _)| c).collect::<String>();
+        assert_eq!(letters, decode(&draw(&letters)).unwrap());
+    }
+
+    #[test]
+    fn it_reports_unknown_glyphs() {
+        let mut frame = draw("EHZ");
+        frame.set(GLYPH_SPACING + 1, 0, true);
+        assert_eq!(
+            OcrError::UnknownGlyph {
+                index: 1,
+                glyph: ["##.#", "#..#", "####", "#..#", "#..#", "#..#"].join("\n"),
+            },
+            decode(&frame).unwrap_err()
+        );
+
+        let frame = Framebuffer::new(10, 5);
+        assert_eq!(OcrError::WrongHeight(5), decode(&frame).unwrap_err());
+    }
+}
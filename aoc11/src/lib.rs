@@ -0,0 +1,707 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use aoc_common::{parse_at, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(notes: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(notes)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_level_of_monkey_business(monkeys)?)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2_level_of_monkey_business(monkeys)?)
+    }
+}
+
+pub type WorryLevel = u128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub id: usize,
+    pub items: Vec<WorryLevel>,
+    pub op: Operation,
+    pub test: Test,
+}
+
+/// How a monkey changes the worry level of an item it inspects, `new = old <op> <operand>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Sub(Operand),
+    Mul(Operand),
+    Div(Operand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Const(WorryLevel),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Test {
+    pub divisible_by: WorryLevel,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+impl Operation {
+    /// The new worry level, `None` if it's too big for `WorryLevel`
+    pub fn apply(&self, old: WorryLevel) -> Option<WorryLevel> {
+        match *self {
+            Operation::Add(arg) => old.checked_add(arg.value(old)),
+            // Worry levels can't go negative
+            Operation::Sub(arg) => Some(old.saturating_sub(arg.value(old))),
+            Operation::Mul(arg) => old.checked_mul(arg.value(old)),
+            // Only `old / old` can divide by zero, constant 0 is rejected by the parser
+            Operation::Div(arg) => Some(old.checked_div(arg.value(old)).unwrap_or(0)),
+        }
+    }
+
+    /// Whether the operation gives the same result modulo n when applied to a level modulo n
+    fn preserves_congruence(&self) -> bool {
+        matches!(self, Operation::Add(_) | Operation::Mul(_))
+    }
+}
+
+impl Operand {
+    fn value(&self, old: WorryLevel) -> WorryLevel {
+        match *self {
+            Operand::Old => old,
+            Operand::Const(n) => n,
+        }
+    }
+}
+
+impl Test {
+    /// Index of the monkey to throw the item to
+    pub fn throw_to(&self, item: WorryLevel) -> usize {
+        if item.is_multiple_of(self.divisible_by) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let items = self.items.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        writeln!(f, "Monkey {}:", self.id)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: {}", self.op)?;
+        write!(f, "{}", self.test)
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (op, arg) = match self {
+            Operation::Add(arg) => ('+', arg),
+            Operation::Sub(arg) => ('-', arg),
+            Operation::Mul(arg) => ('*', arg),
+            Operation::Div(arg) => ('/', arg),
+        };
+        write!(f, "new = old {op} {arg}")
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Old => write!(f, "old"),
+            Operand::Const(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+impl Display for Test {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  Test: divisible by {}", self.divisible_by)?;
+        writeln!(f, "    If true: throw to monkey {}", self.if_true)?;
+        write!(f, "    If false: throw to monkey {}", self.if_false)
+    }
+}
+
+pub fn parse_monkeys(s: &str) -> Result<Vec<Monkey>, ParseError> {
+    let header_re = Regex::new(r"^Monkey (.+):$").unwrap();
+    let op_re = Regex::new(r"^new = old ([-+*/]) (.+)$").unwrap();
+    let tst_div_re = Regex::new(r"^divisible by (.+)$").unwrap();
+    let throw_monkey_re = Regex::new(r"^throw to monkey (.+)$").unwrap();
+    let err = |snippet, msg: &str| ParseError::at(Day11::DAY, s, snippet, msg);
+
+    let monkey_notes = s.split("\n\n").collect::<Vec<_>>();
+    let parse_throw_to = |txt| {
+        let to = throw_monkey_re
+            .captures(txt)
+            .ok_or_else(|| err(txt, "Expected \"throw to monkey <n>\""))?
+            .get(1)
+            .unwrap()
+            .as_str();
+        let to = parse_at::<usize>(Day11::DAY, s, to)?;
+        if to < monkey_notes.len() {
+            Ok(to)
+        } else {
+            Err(err(txt, "No such monkey"))
+        }
+    };
+
+    monkey_notes
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let mut lines = x.lines();
+            let header = lines.next().unwrap_or(x);
+            let id = header_re
+                .captures(header)
+                .ok_or_else(|| err(header, "Expected \"Monkey <n>:\""))?
+                .get(1)
+                .unwrap()
+                .as_str();
+            let id = match parse_at::<usize>(Day11::DAY, s, id)? {
+                n if n == i => n,
+                _ => return Err(err(id, &format!("Expected monkey {i}"))),
+            };
+            let mut monkey_data = |name: &str| {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| err(&x[x.len()..], &format!("Missing \"{name}\" line")))?;
+                match line.split_once(": ") {
+                    Some((field, data)) if field.trim() == name => Ok(data),
+                    _ => Err(err(line, &format!("Expected \"{name}: ...\""))),
+                }
+            };
+
+            let items = monkey_data("Starting items")?
+                .split(',')
+                .map(|itm| parse_at::<WorryLevel>(Day11::DAY, s, itm.trim()))
+                .collect::<Result<_, _>>()?;
+            let op = monkey_data("Operation")?;
+            let caps = op_re
+                .captures(op)
+                .ok_or_else(|| err(op, "Expected \"new = old <+|-|*|/> <n|old>\""))?;
+            let arg = caps.get(2).unwrap().as_str();
+            let arg = match arg {
+                "old" => Operand::Old,
+                _ => Operand::Const(parse_at::<WorryLevel>(Day11::DAY, s, arg)?),
+            };
+            let op = match caps.get(1).unwrap().as_str() {
+                "+" => Operation::Add(arg),
+                "-" => Operation::Sub(arg),
+                "*" => Operation::Mul(arg),
+                _ if arg == Operand::Const(0) => {
+                    return Err(err(caps.get(2).unwrap().as_str(), "Can't divide by 0"))
+                }
+                _ => Operation::Div(arg),
+            };
+            let test = monkey_data("Test")?;
+            let divisible_by = tst_div_re
+                .captures(test)
+                .ok_or_else(|| err(test, "Expected \"divisible by <n>\""))?
+                .get(1)
+                .unwrap()
+                .as_str();
+            let divisible_by = match parse_at::<WorryLevel>(Day11::DAY, s, divisible_by)? {
+                0 => return Err(err(divisible_by, "Can't test divisibility by 0")),
+                n => n,
+            };
+            let if_true = parse_throw_to(monkey_data("If true")?)?;
+            let if_false = parse_throw_to(monkey_data("If false")?)?;
+            Ok(Monkey {
+                id,
+                items,
+                op,
+                test: Test {
+                    divisible_by,
+                    if_true,
+                    if_false,
+                },
+            })
+        })
+        .collect()
+}
+
+pub fn part_1_level_of_monkey_business(monkeys: &[Monkey]) -> Result<u128, SimulationError> {
+    Ok(simulate(monkeys, &SimulationConfig::part_1())?.monkey_business)
+}
+
+pub fn part_2_level_of_monkey_business(monkeys: &[Monkey]) -> Result<u128, SimulationError> {
+    check_congruence(monkeys)?;
+    Ok(simulate(monkeys, &SimulationConfig::part_2(monkeys)?)?.monkey_business)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationConfig {
+    pub rounds: usize,
+    pub relief: Relief,
+    /// Number of most active monkeys whose inspection counts make up the monkey business
+    pub top_k: usize,
+}
+
+/// What happens to the worry level after a monkey inspects an item and gets bored with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    /// Worry levels grow without bound, so simulating overflows after a few hundred rounds
+    None,
+    /// Must not be 0
+    DivideBy(WorryLevel),
+    /// Must not be 0
+    Modulo(WorryLevel),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// Number of items inspected by each monkey
+    pub inspections: Vec<usize>,
+    /// Items held by each monkey after the last round
+    pub items: Vec<Vec<WorryLevel>>,
+    pub monkey_business: u128,
+}
+
+impl SimulationConfig {
+    pub fn part_1() -> Self {
+        SimulationConfig {
+            rounds: 20,
+            relief: Relief::DivideBy(3),
+            top_k: 2,
+        }
+    }
+
+    pub fn part_2(monkeys: &[Monkey]) -> Result<Self, SimulationError> {
+        Ok(SimulationConfig {
+            rounds: 10_000,
+            relief: Relief::Modulo(congruence_modulus(monkeys)?),
+            top_k: 2,
+        })
+    }
+}
+
+impl Relief {
+    /// The relieved worry level, `None` if dividing by 0
+    pub fn apply(&self, level: WorryLevel) -> Option<WorryLevel> {
+        match *self {
+            Relief::None => Some(level),
+            Relief::DivideBy(n) => level.checked_div(n),
+            Relief::Modulo(n) => level.checked_rem(n),
+        }
+    }
+}
+
+pub fn simulate(
+    monkeys: &[Monkey],
+    config: &SimulationConfig,
+) -> Result<SimulationReport, SimulationError> {
+    if config.relief.apply(0).is_none() {
+        return Err(SimulationError::InvalidRelief(config.relief));
+    }
+    let mut items = monkeys.iter().map(|m| m.items.clone()).collect::<Vec<_>>();
+    let mut inspections = vec![0; monkeys.len()];
+    for _round in 0..config.rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let item = monkey
+                    .op
+                    .apply(item)
+                    .ok_or(SimulationError::Overflow { monkey: monkey.id })?;
+                // Checked before simulating
+                let item = config.relief.apply(item).unwrap();
+                items[monkey.test.throw_to(item)].push(item);
+                inspections[i] += 1;
+            }
+        }
+    }
+    let mut most_active = inspections.clone();
+    most_active.sort_unstable_by(|a, b| b.cmp(a));
+    let monkey_business = most_active
+        .iter()
+        .take(config.top_k)
+        .try_fold(1, |product: u128, &n| product.checked_mul(n as u128))
+        .ok_or(SimulationError::MonkeyBusinessOverflow)?;
+    Ok(SimulationReport {
+        monkey_business,
+        inspections,
+        items,
+    })
+}
+
+/// Without relief the worry levels grow too big for any integer type. Every test is
+/// "divisible by n", so keeping the levels modulo the product of all divisors keeps them
+/// small without changing which monkey any item is thrown to (as long as all operations are
+/// `+` or `*`).
+fn congruence_modulus(monkeys: &[Monkey]) -> Result<WorryLevel, SimulationError> {
+    monkeys
+        .iter()
+        .try_fold(1, |product: WorryLevel, m| {
+            product.checked_mul(m.test.divisible_by)
+        })
+        .ok_or(SimulationError::ModulusOverflow)
+}
+
+/// Why the monkeys couldn't be simulated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationError {
+    /// A monkey whose operation can't be applied to worry levels kept modulo the divisors
+    NotCongruent { monkey: usize, op: Operation },
+    /// A monkey's operation made a worry level too big for `WorryLevel`
+    Overflow { monkey: usize },
+    /// The product of all divisors is too big for `WorryLevel`
+    ModulusOverflow,
+    /// Relief dividing by 0
+    InvalidRelief(Relief),
+    /// The product of the inspection counts is too big for `u128`
+    MonkeyBusinessOverflow,
+}
+
+impl Display for SimulationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::NotCongruent { monkey, op } => {
+                write!(f, "Monkey {monkey}: \"{op}\" can't be reduced modulo")
+            }
+            SimulationError::Overflow { monkey } => {
+                write!(f, "Monkey {monkey}: worry level overflowed")
+            }
+            SimulationError::ModulusOverflow => write!(f, "Product of divisors overflowed"),
+            SimulationError::InvalidRelief(relief) => write!(f, "Invalid relief {relief:?}"),
+            SimulationError::MonkeyBusinessOverflow => {
+                write!(f, "Level of monkey business overflowed")
+            }
+        }
+    }
+}
+
+impl Error for SimulationError {}
+
+/// Makes sure reducing worry levels with `congruence_modulus` doesn't change the outcome
+fn check_congruence(monkeys: &[Monkey]) -> Result<(), SimulationError> {
+    match monkeys.iter().find(|m| !m.op.preserves_congruence()) {
+        Some(m) => Err(SimulationError::NotCongruent {
+            monkey: m.id,
+            op: m.op,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// The path of a single item through the monkeys, with worry levels kept modulo the product of
+/// all divisors. Every item moves independently of the others, and since there are finitely many
+/// (monkey, level) states the path always ends up repeating itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    /// What happens to the item in each round, up to the first repeated state
+    pub rounds: Vec<Step>,
+    /// Round where the repeating part starts, `rounds[cycle_start..]` repeats forever
+    pub cycle_start: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// Monkey holding the item at the start of the round
+    pub monkey: usize,
+    /// Worry level of the item at the start of the round
+    pub level: WorryLevel,
+    /// Monkeys that inspected the item during the round, in order
+    pub inspected_by: Vec<usize>,
+}
+
+impl Trajectory {
+    pub fn cycle_len(&self) -> usize {
+        self.rounds.len() - self.cycle_start
+    }
+
+    /// What happens to the item in the given (zero-based) round
+    pub fn round(&self, round: usize) -> &Step {
+        if round < self.rounds.len() {
+            &self.rounds[round]
+        } else {
+            &self.rounds[self.cycle_start + (round - self.cycle_start) % self.cycle_len()]
+        }
+    }
+
+    /// Number of times each monkey inspects the item during the first `rounds` rounds
+    pub fn inspections(&self, rounds: usize, monkey_count: usize) -> Vec<usize> {
+        let mut inspections = vec![0; monkey_count];
+        let mut count = |steps: &[Step], times: usize| {
+            for monkey in steps.iter().flat_map(|s| &s.inspected_by) {
+                inspections[*monkey] += times;
+            }
+        };
+        count(&self.rounds[..rounds.min(self.cycle_start)], 1);
+        if rounds > self.cycle_start {
+            let cycle = &self.rounds[self.cycle_start..];
+            let (full_cycles, rest) = (
+                (rounds - self.cycle_start) / cycle.len(),
+                (rounds - self.cycle_start) % cycle.len(),
+            );
+            count(cycle, full_cycles);
+            count(&cycle[..rest], 1);
+        }
+        inspections
+    }
+}
+
+/// Follows an item, starting with the given monkey, until its state repeats
+pub fn trace_item(
+    monkeys: &[Monkey],
+    monkey: usize,
+    level: WorryLevel,
+) -> Result<Trajectory, SimulationError> {
+    check_congruence(monkeys)?;
+    let modulus = congruence_modulus(monkeys)?;
+    let mut seen = HashMap::new();
+    let mut rounds = Vec::new();
+    let mut state = (monkey, level % modulus);
+    loop {
+        if let Some(&cycle_start) = seen.get(&state) {
+            return Ok(Trajectory {
+                rounds,
+                cycle_start,
+            });
+        }
+        seen.insert(state, rounds.len());
+
+        let (mut monkey, mut level) = state;
+        let mut inspected_by = Vec::new();
+        // The item keeps moving within the round while thrown to monkeys that haven't had their
+        // turn yet
+        let next = loop {
+            inspected_by.push(monkey);
+            level = monkeys[monkey]
+                .op
+                .apply(level)
+                .ok_or(SimulationError::Overflow { monkey })?
+                % modulus;
+            match monkeys[monkey].test.throw_to(level) {
+                next if next > monkey => monkey = next,
+                next => break (next, level),
+            }
+        };
+        rounds.push(Step {
+            monkey: state.0,
+            level: state.1,
+            inspected_by,
+        });
+        state = next;
+    }
+}
+
+/// Level of monkey business without relief after any number of rounds, extrapolating from the
+/// cycle of each item rather than simulating every round. The inspection counts of the two most
+/// active monkeys are multiplied as `u128` since their product overflows `usize` for huge round
+/// counts.
+pub fn level_of_monkey_business_after(
+    monkeys: &[Monkey],
+    rounds: usize,
+) -> Result<u128, SimulationError> {
+    let mut inspections = vec![0; monkeys.len()];
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for item in &monkey.items {
+            let trajectory = trace_item(monkeys, i, *item)?;
+            for (total, n) in inspections
+                .iter_mut()
+                .zip(trajectory.inspections(rounds, monkeys.len()))
+            {
+                *total += n;
+            }
+        }
+    }
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(inspections.iter().take(2).map(|&n| n as u128).product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MONKEYS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_malformed_notes() {
+        let notes = TEST_MONKEYS.replace("new = old + 6", "new = old ^ 6");
+        let err = parse_monkeys(&notes).unwrap_err();
+        assert_eq!((10, 14), (err.line, err.column));
+        assert_eq!("new = old ^ 6", err.snippet);
+
+        let notes = TEST_MONKEYS.replace("throw to monkey 3", "throw to monkey 4");
+        let err = parse_monkeys(&notes).unwrap_err();
+        assert_eq!((6, 15), (err.line, err.column));
+
+        let (truncated, _) = TEST_MONKEYS.split_at(TEST_MONKEYS.rfind("    If false").unwrap());
+        let err = parse_monkeys(truncated).unwrap_err();
+        assert_eq!("", err.snippet);
+        assert_eq!((27, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn it_parses_monkeys() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        assert_eq!(4, monkeys.len());
+        assert_eq!(
+            Monkey {
+                id: 0,
+                items: vec![79, 98],
+                op: Operation::Mul(Operand::Const(19)),
+                test: Test {
+                    divisible_by: 23,
+                    if_true: 2,
+                    if_false: 3,
+                },
+            },
+            monkeys[0]
+        );
+        assert_eq!(Operation::Mul(Operand::Old), monkeys[2].op);
+    }
+
+    #[test]
+    fn it_round_trips_notes() {
+        let notes = TEST_MONKEYS
+            .replace("new = old + 6", "new = old - 6")
+            .replace("new = old * old", "new = old / old");
+        let monkeys = parse_monkeys(&notes).unwrap();
+        assert_eq!(Operation::Sub(Operand::Const(6)), monkeys[1].op);
+        assert_eq!(Operation::Div(Operand::Old), monkeys[2].op);
+
+        let printed = monkeys.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+        let printed = printed.join("\n\n") + "\n";
+        assert_eq!(notes, printed);
+        assert_eq!(monkeys, parse_monkeys(&printed).unwrap());
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        assert_eq!(Ok(10605), part_1_level_of_monkey_business(&monkeys));
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        assert_eq!(Ok(2713310158), part_2_level_of_monkey_business(&monkeys));
+    }
+
+    #[test]
+    fn it_traces_items() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        let trajectory = trace_item(&monkeys, 0, 79).unwrap();
+        let first = Step {
+            monkey: 0,
+            level: 79,
+            inspected_by: vec![0, 3],
+        };
+        assert_eq!(&first, trajectory.round(0));
+        assert_eq!(
+            (1, 1504),
+            (trajectory.round(1).monkey, trajectory.round(1).level)
+        );
+
+        let later = trajectory.cycle_start + 3 * trajectory.cycle_len() + 1;
+        assert_eq!(
+            trajectory.round(trajectory.cycle_start + 1),
+            trajectory.round(later)
+        );
+    }
+
+    #[test]
+    fn it_extrapolates_from_cycles() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        assert_eq!(
+            Ok(2713310158),
+            level_of_monkey_business_after(&monkeys, 10_000)
+        );
+    }
+
+    #[test]
+    fn it_rejects_operations_that_cant_be_reduced() {
+        let notes = TEST_MONKEYS.replace("new = old + 6", "new = old / 7");
+        let monkeys = parse_monkeys(&notes).unwrap();
+        let err = SimulationError::NotCongruent {
+            monkey: 1,
+            op: Operation::Div(Operand::Const(7)),
+        };
+        assert_eq!(Err(err), level_of_monkey_business_after(&monkeys, 5));
+        assert_eq!(Err(err), trace_item(&monkeys, 0, 79));
+        assert_eq!(Err(err), part_2_level_of_monkey_business(&monkeys));
+        assert!(Day11::part2(&monkeys).is_err());
+    }
+
+    #[test]
+    fn it_reports_worry_levels_overflowing() {
+        let notes = TEST_MONKEYS.replace(
+            "new = old + 6",
+            "new = old * 340282366920938463463374607431768211455",
+        );
+        let monkeys = parse_monkeys(&notes).unwrap();
+        let err = SimulationError::Overflow { monkey: 1 };
+        assert_eq!(Err(err), part_1_level_of_monkey_business(&monkeys));
+        assert_eq!(Err(err), part_2_level_of_monkey_business(&monkeys));
+        assert_eq!(Err(err), trace_item(&monkeys, 1, 54));
+        assert!(Day11::part1(&monkeys).is_err());
+
+        let notes = TEST_MONKEYS.replace(
+            "divisible by 23",
+            "divisible by 1000000000000000000000000000000000000",
+        );
+        let monkeys = parse_monkeys(&notes).unwrap();
+        let err = SimulationError::ModulusOverflow;
+        assert_eq!(Err(err), part_2_level_of_monkey_business(&monkeys));
+        assert_eq!(Err(err), level_of_monkey_business_after(&monkeys, 5));
+    }
+
+    #[test]
+    fn it_reports_simulations() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        let report = simulate(&monkeys, &SimulationConfig::part_1()).unwrap();
+        assert_eq!(vec![101, 95, 7, 105], report.inspections);
+        assert_eq!(vec![10, 12, 14, 26, 34], report.items[0]);
+        assert_eq!(vec![245, 93, 53, 199, 115], report.items[1]);
+        assert!(report.items[2].is_empty() && report.items[3].is_empty());
+
+        let config = SimulationConfig {
+            top_k: 3,
+            ..SimulationConfig::part_1()
+        };
+        assert_eq!(
+            105 * 101 * 95,
+            simulate(&monkeys, &config).unwrap().monkey_business
+        );
+    }
+
+    #[test]
+    fn it_rejects_simulations_that_cant_be_run() {
+        let monkeys = parse_monkeys(TEST_MONKEYS).unwrap();
+        for relief in [Relief::DivideBy(0), Relief::Modulo(0)] {
+            let config = SimulationConfig {
+                relief,
+                ..SimulationConfig::part_1()
+            };
+            let err = SimulationError::InvalidRelief(relief);
+            assert_eq!(Err(err), simulate(&monkeys, &config));
+        }
+
+        let config = SimulationConfig {
+            rounds: 1000,
+            relief: Relief::None,
+            top_k: 2,
+        };
+        assert!(matches!(
+            simulate(&monkeys, &config),
+            Err(SimulationError::Overflow { .. })
+        ));
+    }
+}
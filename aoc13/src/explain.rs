@@ -0,0 +1,249 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    slice,
+};
+
+use crate::{ListDisplay, PacketData};
+
+/// How a comparison of two packets was decided, printed like the puzzle's walkthrough
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    /// List indices leading to where the comparison was decided. An int promoted to a list
+    /// counts as a list of one.
+    pub path: Vec<usize>,
+    /// The rule that decided the order, `None` if the packets are equal
+    pub rule: Option<Rule>,
+    /// Ints promoted to lists on the way, by how much of `path` leads to them
+    pub promotions: Vec<(usize, Side)>,
+    /// Each comparison made, with its depth
+    steps: Vec<(usize, String)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Two different ints, left and right
+    IntVsInt(usize, usize),
+    /// One side's list ran out of items first
+    ListRanOut(Side),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Side::Left => write!(f, "left"),
+            Side::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (depth, step)) in self.steps.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}- {step}", "  ".repeat(*depth))?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two packets like `Ord` does, keeping track of every step
+pub fn compare_explained(left: &PacketData, right: &PacketData) -> Explanation {
+    let mut explanation = Explanation {
+        ordering: Ordering::Equal,
+        path: Vec::new(),
+        rule: None,
+        promotions: Vec::new(),
+        steps: Vec::new(),
+    };
+    explanation.ordering = explanation.compare(left, right, 0);
+    explanation
+}
+
+impl Explanation {
+    fn compare(&mut self, left: &PacketData, right: &PacketData, depth: usize) -> Ordering {
+        self.step(depth, format!("Compare {left} vs {right}"));
+        match (left, right) {
+            (PacketData::Int(l), PacketData::Int(r)) => {
+                let ordering = l.cmp(r);
+                if ordering != Ordering::Equal {
+                    self.rule = Some(Rule::IntVsInt(*l, *r));
+                    let smaller = match ordering {
+                        Ordering::Less => Side::Left,
+                        _ => Side::Right,
+                    };
+                    let verdict = verdict(ordering);
+                    self.step(
+                        depth + 1,
+                        format!("{} side is smaller, so {verdict}", capitalized(smaller)),
+                    );
+                }
+                ordering
+            }
+            (PacketData::List(l), PacketData::List(r)) => self.compare_lists(l, r, depth + 1),
+            (PacketData::Int(_), PacketData::List(r)) => {
+                self.compare_promoted(Side::Left, slice::from_ref(left), r, depth + 1)
+            }
+            (PacketData::List(l), PacketData::Int(_)) => {
+                self.compare_promoted(Side::Right, l, slice::from_ref(right), depth + 1)
+            }
+        }
+    }
+
+    fn compare_promoted(
+        &mut self,
+        side: Side,
+        left: &[PacketData],
+        right: &[PacketData],
+        depth: usize,
+    ) -> Ordering {
+        let promoted = match side {
+            Side::Left => &left[0],
+            Side::Right => &right[0],
+        };
+        self.step(
+            depth,
+            format!("Mixed types; convert {side} to [{promoted}] and retry comparison"),
+        );
+        self.step(
+            depth,
+            format!("Compare {} vs {}", ListDisplay(left), ListDisplay(right)),
+        );
+        self.promotions.push((self.path.len(), side));
+        let ordering = self.compare_lists(left, right, depth + 1);
+        if ordering == Ordering::Equal {
+            self.promotions.pop();
+        }
+        ordering
+    }
+
+    fn compare_lists(
+        &mut self,
+        left: &[PacketData],
+        right: &[PacketData],
+        depth: usize,
+    ) -> Ordering {
+        for (i, (l, r)) in left.iter().zip(right).enumerate() {
+            self.path.push(i);
+            let ordering = self.compare(l, r, depth);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            self.path.pop();
+        }
+
+        let ordering = left.len().cmp(&right.len());
+        if ordering != Ordering::Equal {
+            let ran_out = match ordering {
+                Ordering::Less => Side::Left,
+                _ => Side::Right,
+            };
+            self.rule = Some(Rule::ListRanOut(ran_out));
+            let verdict = verdict(ordering);
+            self.step(
+                depth,
+                format!(
+                    "{} side ran out of items, so {verdict}",
+                    capitalized(ran_out)
+                ),
+            );
+        }
+        ordering
+    }
+
+    fn step(&mut self, depth: usize, step: String) {
+        self.steps.push((depth, step));
+    }
+}
+
+fn verdict(ordering: Ordering) -> &'static str {
+    match ordering {
+        Ordering::Less => "inputs are in the right order",
+        _ => "inputs are not in the right order",
+    }
+}
+
+fn capitalized(side: Side) -> &'static str {
+    match side {
+        Side::Left => "Left",
+        Side::Right => "Right",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+
+    const TEST_PACKETS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_explains_promotions() {
+        let pairs = Day13::parse(TEST_PACKETS).unwrap();
+        let explanation = compare_explained(&pairs[1].0, &pairs[1].1);
+        assert_eq!(
+            "\
+- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4] and retry comparison
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller, so inputs are in the right order",
+            explanation.to_string()
+        );
+        assert_eq!(Ordering::Less, explanation.ordering);
+        assert_eq!(vec![1, 0], explanation.path);
+        assert_eq!(Some(Rule::IntVsInt(2, 4)), explanation.rule);
+        assert_eq!(vec![(1, Side::Right)], explanation.promotions);
+    }
+
+    #[test]
+    fn it_explains_lists_running_out() {
+        let pairs = Day13::parse(TEST_PACKETS).unwrap();
+        let explanation = compare_explained(&pairs[3].0, &pairs[3].1);
+        assert_eq!(
+            "\
+- Compare [[4,4],4,4] vs [[4,4],4,4,4]
+  - Compare [4,4] vs [4,4]
+    - Compare 4 vs 4
+    - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Left side ran out of items, so inputs are in the right order",
+            explanation.to_string()
+        );
+        assert_eq!(Vec::<usize>::new(), explanation.path);
+        assert_eq!(Some(Rule::ListRanOut(Side::Left)), explanation.rule);
+
+        let explanation = compare_explained(&pairs[6].0, &pairs[6].1);
+        assert_eq!(Ordering::Greater, explanation.ordering);
+        assert_eq!(vec![0], explanation.path);
+        assert_eq!(Some(Rule::ListRanOut(Side::Right)), explanation.rule);
+    }
+
+    #[test]
+    fn it_agrees_with_ord() {
+        let pairs = Day13::parse(TEST_PACKETS).unwrap();
+        for (left, right) in &pairs {
+            assert_eq!(left.cmp(right), compare_explained(left, right).ordering);
+        }
+
+        let packet = "[[1],2]".parse::<PacketData>().unwrap();
+        let explanation = compare_explained(&packet, &"[1,2]".parse().unwrap());
+        assert_eq!(Ordering::Equal, explanation.ordering);
+        assert_eq!(None, explanation.rule);
+        assert!(explanation.promotions.is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    slice,
+};
+
+use aoc_common::{ParseError, Solution};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+mod explain;
+mod parser;
+
+pub use explain::{compare_explained, Explanation, Rule, Side};
+pub use parser::{PacketError, PacketErrorKind, MAX_DEPTH};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(PacketData, PacketData)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(packets: &str) -> Result<Self::Input, ParseError> {
+        let parse_packet = |packet: &str| {
+            packet.parse::<PacketData>().map_err(|e| {
+                let rest = &packet[e.position..];
+                let at = &rest[..rest.chars().next().map_or(0, char::len_utf8)];
+                ParseError::at(Self::DAY, packets, at, e.kind.to_string())
+            })
+        };
+
+        packets
+            .split("\n\n")
+            .map(|pair| {
+                if let [left, right] = pair.lines().collect::<Vec<_>>()[..] {
+                    Ok((parse_packet(left)?, parse_packet(right)?))
+                } else {
+                    Err(ParseError::at(
+                        Self::DAY,
+                        packets,
+                        pair,
+                        "Expected a pair of packets",
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_sum_correct_indices(pairs))
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2_find_decoder_key(pairs))
+    }
+}
+
+/// A packet or part of one. Serializes to the JSON it's written as, `[1,[2,3]]`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PacketData {
+    List(Vec<PacketData>),
+    Int(usize),
+}
+
+impl Display for PacketData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketData::Int(int) => write!(f, "{int}"),
+            PacketData::List(items) => write!(f, "{}", ListDisplay(items)),
+        }
+    }
+}
+
+/// Displays a slice of packets as a list, without copying it into a `PacketData::List`
+pub(crate) struct ListDisplay<'a>(pub(crate) &'a [PacketData]);
+
+impl Display for ListDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{item}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(left), Self::Int(right)) => left.cmp(right),
+            // Slices compare item by item, then by length, just like packets
+            (Self::List(left), Self::List(right)) => left.as_slice().cmp(right),
+            // An int is compared as a list holding only that int, borrowed rather than built
+            (Self::Int(_), Self::List(right)) => slice::from_ref(self).cmp(right),
+            (Self::List(left), Self::Int(_)) => left.as_slice().cmp(slice::from_ref(other)),
+        }
+    }
+}
+
+pub fn part_1_sum_correct_indices(pairs: &[(PacketData, PacketData)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(i, _)| i + 1)
+        .sum()
+}
+
+pub fn part_2_find_decoder_key(pairs: &[(PacketData, PacketData)]) -> usize {
+    static DIVIDER_PACKETS: Lazy<[PacketData; 2]> = Lazy::new(|| {
+        [
+            "[[2]]".parse::<PacketData>().unwrap(),
+            "[[6]]".parse::<PacketData>().unwrap(),
+        ]
+    });
+
+    let packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left, right])
+        .collect::<Vec<_>>();
+
+    // Each divider ends up after the packets and dividers smaller than it, so there's no
+    // need to sort everything to find where
+    DIVIDER_PACKETS
+        .iter()
+        .enumerate()
+        .map(|(i, divider)| i + 1 + packets.iter().filter(|&&p| p < divider).count())
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const TEST_PACKETS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_parses_simple_packets() {
+        use PacketData::{Int, List};
+        assert_eq!(Ok(List(vec![Int(1), Int(1), Int(3)])), "[1,1,3]".parse());
+        assert_eq!(Ok(List(vec![])), "[]".parse());
+    }
+
+    #[test]
+    fn it_parses_nested_packets() {
+        use PacketData::{Int, List};
+        assert_eq!(
+            Ok(List(vec![
+                List(vec![Int(1)]),
+                List(vec![Int(2), Int(3), Int(4)])
+            ])),
+            "[[1],[2,3,4]]".parse()
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_packets() {
+        let err = Day13::parse("[1,2]\n[3,4]\n\n[1,x]\n[2]").unwrap_err();
+        assert_eq!((4, 4, "x"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = Day13::parse("[1,2]\n[3,4]\n\n[1]").unwrap_err();
+        assert_eq!(4, err.line);
+    }
+
+    #[test]
+    fn it_prints_packets() {
+        for packet in TEST_PACKETS.lines().filter(|line| !line.is_empty()) {
+            assert_eq!(packet, packet.parse::<PacketData>().unwrap().to_string());
+        }
+        assert_eq!(
+            "[[],1]",
+            " [ [ ] , 1 ] ".parse::<PacketData>().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn it_round_trips_through_json() {
+        let pairs = Day13::parse(TEST_PACKETS).unwrap();
+        let json = serde_json::to_string(&pairs).unwrap();
+        assert!(json.starts_with("[[[1,1,3,1,1],[1,1,5,1,1]],[[[1],[2,3,4]],[[1],4]],"));
+        assert_eq!(pairs, serde_json::from_str::<Vec<_>>(&json).unwrap());
+
+        let packet = serde_json::from_str::<PacketData>(" [1, [2, 3], []]").unwrap();
+        assert_eq!("[1,[2,3],[]]", packet.to_string());
+        assert!(serde_json::from_str::<PacketData>("[1, -2]").is_err());
+        assert!(serde_json::from_str::<PacketData>("[\"a\"]").is_err());
+    }
+
+    #[test]
+    fn it_compares_correctly() {
+        assert_eq!(
+            Ordering::Greater,
+            "[1,2,3]"
+                .parse::<PacketData>()
+                .unwrap()
+                .cmp(&"[1,2,2]".parse::<PacketData>().unwrap())
+        );
+
+        assert_eq!(
+            Ordering::Greater,
+            "[1,2,3,4]"
+                .parse::<PacketData>()
+                .unwrap()
+                .cmp(&"[1,2,3]".parse::<PacketData>().unwrap())
+        );
+
+        assert_eq!(
+            Ordering::Greater,
+            "[2,2,3]"
+                .parse::<PacketData>()
+                .unwrap()
+                .cmp(&"[1,2,3]".parse::<PacketData>().unwrap())
+        );
+
+        assert_eq!(
+            Ordering::Greater,
+            "2".parse::<PacketData>()
+                .unwrap()
+                .cmp(&"[1]".parse::<PacketData>().unwrap())
+        );
+    }
+
+    fn packet() -> impl Strategy<Value = PacketData> {
+        // Few distinct ints and short lists, so packets often share a prefix or are equal
+        let int = (0..4usize).prop_map(PacketData::Int);
+        int.prop_recursive(4, 32, 4, |inner| {
+            prop::collection::vec(inner, 0..4).prop_map(PacketData::List)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn it_orders_reflexively(a in packet()) {
+            prop_assert_eq!(Ordering::Equal, a.cmp(&a));
+        }
+
+        #[test]
+        fn it_orders_antisymmetrically(a in packet(), b in packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+
+        #[test]
+        fn it_orders_transitively(a in packet(), b in packet(), c in packet()) {
+            let mut packets = [a, b, c];
+            packets.sort();
+            let [a, b, c] = &packets;
+            prop_assert_ne!(Ordering::Greater, a.cmp(b));
+            prop_assert_ne!(Ordering::Greater, b.cmp(c));
+            prop_assert_ne!(Ordering::Greater, a.cmp(c));
+            if a.cmp(b) == Ordering::Equal && b.cmp(c) == Ordering::Equal {
+                prop_assert_eq!(Ordering::Equal, a.cmp(c));
+            }
+        }
+    }
+
+    #[test]
+    fn it_works_with_example_1() {
+        assert_eq!(
+            13,
+            part_1_sum_correct_indices(&Day13::parse(TEST_PACKETS).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_works_with_example_2() {
+        assert_eq!(
+            140,
+            part_2_find_decoder_key(&Day13::parse(TEST_PACKETS).unwrap())
+        );
+    }
+}
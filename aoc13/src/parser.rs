@@ -0,0 +1,199 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::PacketData;
+
+/// Deepest nesting of lists accepted. Comparing, printing and dropping packets recurse into
+/// nested lists, so this keeps them well within the stack.
+pub const MAX_DEPTH: usize = 1_000;
+
+/// Malformed packet, with the byte offset in the packet where it went wrong
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketError {
+    pub position: usize,
+    pub kind: PacketErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketErrorKind {
+    /// A `[` that's never closed, or a `]` that closes nothing
+    UnbalancedBracket,
+    /// A `,` right before the `]` closing its list
+    TrailingComma,
+    UnexpectedChar(char),
+    /// The packet is empty
+    UnexpectedEnd,
+    /// An integer too large for `usize`
+    IntOverflow,
+    /// Lists nested deeper than `MAX_DEPTH`
+    TooDeep,
+}
+
+impl Display for PacketErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketErrorKind::UnbalancedBracket => write!(f, "Unbalanced bracket"),
+            PacketErrorKind::TrailingComma => write!(f, "Trailing comma"),
+            PacketErrorKind::UnexpectedChar(c) => write!(f, "Unexpected character {c:?}"),
+            PacketErrorKind::UnexpectedEnd => write!(f, "Expected an integer or a list"),
+            PacketErrorKind::IntOverflow => write!(f, "Integer too large"),
+            PacketErrorKind::TooDeep => write!(f, "Lists nested deeper than {MAX_DEPTH}"),
+        }
+    }
+}
+
+impl Display for PacketError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl Error for PacketError {}
+
+/// What the parser has just seen
+enum State {
+    Start,
+    Open,
+    /// A comma, at the given position
+    Comma(usize),
+    Value(PacketData),
+}
+
+impl FromStr for PacketData {
+    type Err = PacketError;
+
+    /// Parses in a single pass over the bytes, keeping the lists being built on a stack
+    /// rather than recursing. Nesting deeper than `MAX_DEPTH` is rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        let error = |position, kind| Err(PacketError { position, kind });
+        // Lists that are still open, with the position of their `[`
+        let mut open: Vec<(usize, Vec<PacketData>)> = Vec::new();
+        let mut state = State::Start;
+        let mut pos = 0;
+
+        loop {
+            while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+                pos += 1;
+            }
+            let byte = bytes.get(pos).copied();
+            state = match (state, byte) {
+                (State::Value(value), None) if open.is_empty() => return Ok(value),
+                (_, None) => match open.last() {
+                    Some(&(bracket, _)) => {
+                        return error(bracket, PacketErrorKind::UnbalancedBracket)
+                    }
+                    None => return error(pos, PacketErrorKind::UnexpectedEnd),
+                },
+                (State::Start | State::Open | State::Comma(_), Some(b'[')) => {
+                    if open.len() == MAX_DEPTH {
+                        return error(pos, PacketErrorKind::TooDeep);
+                    }
+                    open.push((pos, Vec::new()));
+                    State::Open
+                }
+                (State::Start | State::Open | State::Comma(_), Some(b'0'..=b'9')) => {
+                    let mut int: usize = 0;
+                    while let Some(&digit @ b'0'..=b'9') = bytes.get(pos) {
+                        int = match int
+                            .checked_mul(10)
+                            .and_then(|int| int.checked_add((digit - b'0') as usize))
+                        {
+                            Some(int) => int,
+                            None => return error(pos, PacketErrorKind::IntOverflow),
+                        };
+                        pos += 1;
+                    }
+                    state = State::Value(PacketData::Int(int));
+                    continue;
+                }
+                (State::Open, Some(b']')) => {
+                    let (_, list) = open.pop().unwrap();
+                    State::Value(PacketData::List(list))
+                }
+                (State::Comma(comma), Some(b']')) => {
+                    return error(comma, PacketErrorKind::TrailingComma)
+                }
+                (State::Value(value), Some(b',')) if !open.is_empty() => {
+                    open.last_mut().unwrap().1.push(value);
+                    State::Comma(pos)
+                }
+                (State::Value(value), Some(b']')) => match open.pop() {
+                    Some((_, mut list)) => {
+                        list.push(value);
+                        State::Value(PacketData::List(list))
+                    }
+                    None => return error(pos, PacketErrorKind::UnbalancedBracket),
+                },
+                (State::Start, Some(b']')) => {
+                    return error(pos, PacketErrorKind::UnbalancedBracket)
+                }
+                (_, Some(_)) => {
+                    let c = s[pos..].chars().next().unwrap();
+                    return error(pos, PacketErrorKind::UnexpectedChar(c));
+                }
+            };
+            pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(items: impl IntoIterator<Item = PacketData>) -> PacketData {
+        PacketData::List(items.into_iter().collect())
+    }
+
+    fn error(position: usize, kind: PacketErrorKind) -> Result<PacketData, PacketError> {
+        Err(PacketError { position, kind })
+    }
+
+    #[test]
+    fn it_parses_packets() {
+        use PacketData::Int;
+        assert_eq!(Ok(list([])), "[]".parse());
+        assert_eq!(Ok(Int(10)), "10".parse());
+        assert_eq!(
+            Ok(list([list([Int(1)]), list([Int(2), Int(3), list([])])])),
+            " [ [1] ,\t[2, 3,[]] ] ".parse()
+        );
+    }
+
+    #[test]
+    fn it_limits_nesting() {
+        let nested = |depth| format!("{}7{}", "[".repeat(depth), "]".repeat(depth));
+        let deepest = nested(MAX_DEPTH).parse::<PacketData>().unwrap();
+        let other = nested(MAX_DEPTH).replace('7', "8").parse().unwrap();
+        assert!(deepest < other);
+        assert_eq!(nested(MAX_DEPTH), deepest.to_string());
+
+        assert_eq!(
+            error(MAX_DEPTH, PacketErrorKind::TooDeep),
+            nested(MAX_DEPTH + 1).parse()
+        );
+        assert_eq!(
+            error(MAX_DEPTH, PacketErrorKind::TooDeep),
+            "[".repeat(100_000).parse()
+        );
+    }
+
+    #[test]
+    fn it_reports_positions() {
+        use PacketErrorKind::*;
+        assert_eq!(error(0, UnbalancedBracket), "[1,[2,3]".parse());
+        assert_eq!(error(5, UnbalancedBracket), "[[1],[2".parse());
+        assert_eq!(error(5, UnbalancedBracket), "[1,2]]".parse());
+        assert_eq!(error(4, TrailingComma), "[1,2,]".parse());
+        assert_eq!(error(1, UnexpectedChar(',')), "[,1]".parse());
+        assert_eq!(error(3, UnexpectedChar('x')), "[1,x]".parse());
+        assert_eq!(error(3, UnexpectedChar('2')), "[1 2]".parse());
+        assert_eq!(error(3, UnexpectedChar('é')), "[1,é]".parse());
+        assert_eq!(error(1, UnexpectedEnd), " ".parse());
+        assert_eq!(error(20, IntOverflow), "[99999999999999999999999]".parse());
+    }
+}
@@ -0,0 +1,108 @@
+use std::{collections::HashMap, error::Error, str::FromStr};
+
+use aoc_common::{ParseError, Solution, Unsolved};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, MonkeyJob>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        s.lines()
+            .map(|l| {
+                let (monkey, job) = l.split_once(": ").ok_or_else(|| {
+                    ParseError::at(Self::DAY, s, l, "Expected \"<monkey>: <job>\"")
+                })?;
+                let job = job
+                    .parse::<MonkeyJob>()
+                    .map_err(|e| ParseError::at(Self::DAY, s, job, e))?;
+                Ok((monkey.to_string(), job))
+            })
+            .collect()
+    }
+
+    fn part1(monkey_jobs: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        part_1_find_root_num(monkey_jobs)
+    }
+
+    fn part2(_monkey_jobs: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err(Unsolved.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MonkeyJob {
+    Number(usize),
+    Operation(String),
+}
+
+impl FromStr for MonkeyJob {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(num) = s.parse::<usize>() {
+            Ok(MonkeyJob::Number(num))
+        } else if let [_, "+" | "-" | "*" | "/", _] = s.split_whitespace().collect::<Vec<_>>()[..] {
+            Ok(MonkeyJob::Operation(s.to_string()))
+        } else {
+            Err("Expected a number or an operation like \"aaaa + bbbb\"".to_string())
+        }
+    }
+}
+
+pub fn part_1_find_root_num(
+    monkey_jobs: &HashMap<String, MonkeyJob>,
+) -> Result<usize, Box<dyn Error>> {
+    fn monkey_yell(map: &HashMap<String, MonkeyJob>, name: &str) -> Result<usize, String> {
+        let job = map
+            .get(name)
+            .ok_or_else(|| format!("Monkey {name} not found"))?;
+
+        match job.clone() {
+            MonkeyJob::Number(num) => Ok(num),
+            MonkeyJob::Operation(op) => {
+                if let [monkey1, op, monkey2] = op.split_whitespace().collect::<Vec<_>>()[..] {
+                    match op {
+                        "+" => Ok(monkey_yell(map, monkey1)? + monkey_yell(map, monkey2)?),
+                        "-" => Ok(monkey_yell(map, monkey1)? - monkey_yell(map, monkey2)?),
+                        "*" => Ok(monkey_yell(map, monkey1)? * monkey_yell(map, monkey2)?),
+                        "/" => Ok(monkey_yell(map, monkey1)? / monkey_yell(map, monkey2)?),
+                        _ => Err(format!("Unexpected operation {op}")),
+                    }
+                } else {
+                    Err(format!("Unexpected format for operation {op}"))
+                }
+            }
+        }
+    }
+
+    Ok(monkey_yell(monkey_jobs, "root")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MONKEY_JOBS: &str = include_str!("../example.txt");
+
+    #[test]
+    fn it_reports_invalid_jobs() {
+        let err = Day21::parse("root: pppw + sjmn\ndbpl: 5\ncczh: sllz %% lgvd").unwrap_err();
+        assert_eq!(
+            (3, 7, "sllz %% lgvd"),
+            (err.line, err.column, err.snippet.as_str())
+        );
+    }
+
+    #[test]
+    fn it_works_example_1() {
+        assert_eq!(
+            152,
+            part_1_find_root_num(&Day21::parse(TEST_MONKEY_JOBS).unwrap()).unwrap()
+        )
+    }
+}
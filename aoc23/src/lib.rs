@@ -1,17 +1,71 @@
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fs,
 };
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input.txt")?;
-    println!("{}", part_1_count_empty_tiles_after_elf_diffusion(&input));
-    Ok(())
+use aoc_common::{ParseError, Solution, Unsolved};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Vec<Position>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(state: &str) -> Result<Self::Input, ParseError> {
+        let width = state.lines().next().unwrap_or_default().len();
+        let mut elves = Vec::new();
+        for (y, row) in state.lines().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::at(
+                    Self::DAY,
+                    state,
+                    row,
+                    format!("Expected all rows to be {width} tiles wide"),
+                ));
+            }
+            for (x, tile) in row.char_indices() {
+                match tile {
+                    '#' => elves.push(Position {
+                        x: x as isize,
+                        y: y as isize,
+                    }),
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError::at(
+                            Self::DAY,
+                            state,
+                            &row[x..x + tile.len_utf8()],
+                            "Expected '#' (elf) or '.' (ground)",
+                        ))
+                    }
+                }
+            }
+        }
+        if elves.is_empty() {
+            return Err(ParseError::at(
+                Self::DAY,
+                state,
+                &state[state.len()..],
+                "No elves in input",
+            ));
+        }
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1_count_empty_tiles_after_elf_diffusion(elves))
+    }
+
+    fn part2(_elves: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err(Unsolved.into())
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-struct Position {
+pub struct Position {
     x: isize,
     y: isize,
 }
@@ -35,19 +89,8 @@ impl Direction {
     }
 }
 
-fn part_1_count_empty_tiles_after_elf_diffusion(state: &str) -> usize {
-    let width = state.lines().next().unwrap().len();
-    let elves = state
-        .chars()
-        .filter(|&c| !c.is_whitespace())
-        .enumerate()
-        .filter(|&(_i, x)| x == '#')
-        .map(|(i, _x)| Position {
-            x: (i % width) as isize,
-            y: (i / width) as isize,
-        })
-        .collect::<Vec<_>>();
-
+pub fn part_1_count_empty_tiles_after_elf_diffusion(elves: &[Position]) -> usize {
+    let elves = elves.to_vec();
     let (final_elves, _) = (0..10).fold((elves, Direction::North), |(elves, dir), _el| {
         // Step 1 - propose new positions
         let mut proposed_moves = HashMap::new();
@@ -166,7 +209,6 @@ fn part_1_count_empty_tiles_after_elf_diffusion(state: &str) -> usize {
     });
 
     // Find empty spaces
-    print_elves(&final_elves);
     let final_elves_map = final_elves.iter().copied().collect::<HashSet<_>>();
     let mut empty_ground_count = 0;
     for y in final_elves.iter().min_by_key(|&e| e.y).unwrap().y
@@ -179,54 +221,23 @@ fn part_1_count_empty_tiles_after_elf_diffusion(state: &str) -> usize {
                 empty_ground_count += 1;
             }
         }
-        println!();
     }
 
     empty_ground_count
 }
 
-fn print_elves(elves: &[Position]) {
-    println!();
-    let map = elves.iter().copied().collect::<HashSet<_>>();
-    for y in
-        elves.iter().min_by_key(|&e| e.y).unwrap().y..=elves.iter().max_by_key(|&e| e.y).unwrap().y
-    {
-        for x in elves.iter().min_by_key(|&e| e.x).unwrap().x
-            ..=elves.iter().max_by_key(|&e| e.x).unwrap().x
-        {
-            if map.contains(&Position { x, y }) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!();
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const SMALLER_TEST_ELVES: &'static str = ".....
+    const SMALLER_TEST_ELVES: &str = ".....
 ..##.
 ..#..
 .....
 ..##.
 .....";
 
-    const TEST_ELVES: &'static str = "..............
-..............
-.......#......
-.....###.#....
-...#...#.#....
-....#...##....
-...#.###......
-...##.#.##....
-....#..#......
-..............
-..............
-..............";
+    const TEST_ELVES: &str = include_str!("../example.txt");
 
     #[test]
     fn it_works_position() {
@@ -242,11 +253,21 @@ mod tests {
             .collect::<Vec<_>>();
     }
 
+    #[test]
+    fn it_works_with_smaller_example() {
+        assert_eq!(
+            25,
+            part_1_count_empty_tiles_after_elf_diffusion(
+                &Day23::parse(SMALLER_TEST_ELVES).unwrap()
+            )
+        )
+    }
+
     #[test]
     fn it_works_with_example_1() {
         assert_eq!(
             110,
-            part_1_count_empty_tiles_after_elf_diffusion(TEST_ELVES)
+            part_1_count_empty_tiles_after_elf_diffusion(&Day23::parse(TEST_ELVES).unwrap())
         )
     }
 }